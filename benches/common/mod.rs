@@ -0,0 +1,35 @@
+//! Minimal benchmarking harness so the benches build without any extra dependencies
+
+use std::time::{Duration, Instant};
+
+const TARGET_TIME: Duration = Duration::from_millis(500);
+
+/// Prevents the compiler from optimising away the computation of `value`
+pub fn black_box<T>(value: T) -> T {
+    // SAFETY: `value` is valid for reads and is forgotten so it is not dropped twice
+    unsafe {
+        let result = std::ptr::read_volatile(&value);
+        std::mem::forget(value);
+        result
+    }
+}
+
+/// Runs `f` repeatedly and prints the mean time per iteration
+pub fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up and estimate how many iterations fit in the target time
+    let start = Instant::now();
+    let mut iters = 0u32;
+    while start.elapsed() < TARGET_TIME / 10 {
+        f();
+        iters += 1;
+    }
+    let iters = iters.saturating_mul(10);
+
+    let start = Instant::now();
+    for _ in 0..iters {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:<48} {:>12.1?}/iter", name, elapsed / iters);
+}
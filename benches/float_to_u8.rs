@@ -0,0 +1,51 @@
+mod common;
+
+use common::{bench, black_box};
+use saturate::SaturatingFrom;
+
+const LEN: usize = 1 << 16;
+
+// Hand written clamps for comparison with the `as` cast used by the SaturatingFrom impls. `clamp`
+// can't be used since it propagates NaN
+#[allow(clippy::manual_clamp)]
+fn manual_clamp_f32(value: f32) -> u8 {
+    value.max(0.0).min(255.0) as i32 as u8
+}
+
+#[allow(clippy::manual_clamp)]
+fn manual_clamp_f64(value: f64) -> u8 {
+    value.max(0.0).min(255.0) as i32 as u8
+}
+
+fn main() {
+    let src_f32: Vec<_> = (0..LEN as i32)
+        .map(|i| (i.wrapping_mul(7919) % 1000 - 300) as f32 * 0.75)
+        .collect();
+    let src_f64: Vec<_> = src_f32.iter().map(|&f| f64::from(f)).collect();
+    let mut dst = vec![0u8; LEN];
+
+    bench("u8::saturating_from(f32)", || {
+        for (dst, &src) in dst.iter_mut().zip(black_box(&src_f32)) {
+            *dst = u8::saturating_from(src);
+        }
+        black_box(&mut dst);
+    });
+    bench("manual clamp f32 -> u8", || {
+        for (dst, &src) in dst.iter_mut().zip(black_box(&src_f32)) {
+            *dst = manual_clamp_f32(src);
+        }
+        black_box(&mut dst);
+    });
+    bench("u8::saturating_from(f64)", || {
+        for (dst, &src) in dst.iter_mut().zip(black_box(&src_f64)) {
+            *dst = u8::saturating_from(src);
+        }
+        black_box(&mut dst);
+    });
+    bench("manual clamp f64 -> u8", || {
+        for (dst, &src) in dst.iter_mut().zip(black_box(&src_f64)) {
+            *dst = manual_clamp_f64(src);
+        }
+        black_box(&mut dst);
+    });
+}
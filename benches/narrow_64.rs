@@ -0,0 +1,67 @@
+mod common;
+
+use common::{bench, black_box};
+use saturate::{saturating_convert_slice, SaturatingFrom};
+
+const LEN: usize = 1 << 16;
+
+// Hand written branchless clamps for comparison with the clamp used by the SaturatingFrom impls
+fn branchless_i64_to_i32(value: i64) -> i32 {
+    // The high bits (including the sign bit of the low half) are all equal iff the value fits
+    let fits = (value >> 31) == (value >> 63);
+    let saturated = ((value >> 63) as i32) ^ i32::MAX;
+    if fits {
+        value as i32
+    } else {
+        saturated
+    }
+}
+
+fn branchless_u64_to_u32(value: u64) -> u32 {
+    // All ones if any of the high bits are set, otherwise zero
+    let overflow = 0u32.wrapping_sub(((value >> 32) != 0) as u32);
+    value as u32 | overflow
+}
+
+fn main() {
+    let src_i64: Vec<_> = (0..LEN as i64)
+        .map(|i| (i.wrapping_mul(7919) % 1000 - 300) << 24)
+        .collect();
+    let src_u64: Vec<_> = src_i64.iter().map(|&i| i.unsigned_abs()).collect();
+    let mut dst_i32 = vec![0i32; LEN];
+    let mut dst_u32 = vec![0u32; LEN];
+
+    bench("i32::saturating_from(i64)", || {
+        for (dst, &src) in dst_i32.iter_mut().zip(black_box(&src_i64)) {
+            *dst = i32::saturating_from(src);
+        }
+        black_box(&mut dst_i32);
+    });
+    bench("branchless i64 -> i32", || {
+        for (dst, &src) in dst_i32.iter_mut().zip(black_box(&src_i64)) {
+            *dst = branchless_i64_to_i32(src);
+        }
+        black_box(&mut dst_i32);
+    });
+    bench("saturating_convert_slice i64 -> i32", || {
+        saturating_convert_slice(black_box(&src_i64[..]), &mut dst_i32);
+        black_box(&mut dst_i32);
+    });
+
+    bench("u32::saturating_from(u64)", || {
+        for (dst, &src) in dst_u32.iter_mut().zip(black_box(&src_u64)) {
+            *dst = u32::saturating_from(src);
+        }
+        black_box(&mut dst_u32);
+    });
+    bench("branchless u64 -> u32", || {
+        for (dst, &src) in dst_u32.iter_mut().zip(black_box(&src_u64)) {
+            *dst = branchless_u64_to_u32(src);
+        }
+        black_box(&mut dst_u32);
+    });
+    bench("saturating_convert_slice u64 -> u32", || {
+        saturating_convert_slice(black_box(&src_u64[..]), &mut dst_u32);
+        black_box(&mut dst_u32);
+    });
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{bench, black_box};
+use saturate::SaturatingFrom;
+
+const LEN: usize = 1 << 16;
+
+// Explicit min/max clamps for comparison with the Ord::clamp used by the SaturatingFrom impls
+fn min_max_i64_to_i32(value: i64) -> i32 {
+    value.min(i64::from(i32::MAX)).max(i64::from(i32::MIN)) as i32
+}
+
+fn min_max_i32_to_u8(value: i32) -> u8 {
+    value.min(i32::from(u8::MAX)).max(i32::from(u8::MIN)) as u8
+}
+
+fn main() {
+    let src_i64: Vec<_> = (0..LEN as i64)
+        .map(|i| (i.wrapping_mul(7919) % 1000 - 300) << 24)
+        .collect();
+    let src_i32: Vec<_> = (0..LEN as i32)
+        .map(|i| i.wrapping_mul(7919) % 1000 - 300)
+        .collect();
+    let mut dst_i32 = vec![0i32; LEN];
+    let mut dst_u8 = vec![0u8; LEN];
+
+    bench("i32::saturating_from(i64)", || {
+        for (dst, &src) in dst_i32.iter_mut().zip(black_box(&src_i64)) {
+            *dst = i32::saturating_from(src);
+        }
+        black_box(&mut dst_i32);
+    });
+    bench("min/max i64 -> i32", || {
+        for (dst, &src) in dst_i32.iter_mut().zip(black_box(&src_i64)) {
+            *dst = min_max_i64_to_i32(src);
+        }
+        black_box(&mut dst_i32);
+    });
+
+    bench("u8::saturating_from(i32)", || {
+        for (dst, &src) in dst_u8.iter_mut().zip(black_box(&src_i32)) {
+            *dst = u8::saturating_from(src);
+        }
+        black_box(&mut dst_u8);
+    });
+    bench("min/max i32 -> u8", || {
+        for (dst, &src) in dst_u8.iter_mut().zip(black_box(&src_i32)) {
+            *dst = min_max_i32_to_u8(src);
+        }
+        black_box(&mut dst_u8);
+    });
+}
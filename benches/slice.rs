@@ -0,0 +1,37 @@
+mod common;
+
+use common::{bench, black_box};
+use saturate::saturating_convert_slice;
+
+const LEN: usize = 1 << 16;
+
+fn main() {
+    let src_i32: Vec<_> = (0..LEN as i32)
+        .map(|i| i.wrapping_mul(7919) % 1000 - 300)
+        .collect();
+    let src_f32: Vec<_> = src_i32.iter().map(|&i| i as f32 * 0.75).collect();
+    let mut dst = vec![0u8; LEN];
+
+    bench("saturating_convert_slice i32 -> u8", || {
+        saturating_convert_slice(black_box(&src_i32[..]), &mut dst);
+        black_box(&mut dst);
+    });
+    bench("saturating_convert_slice f32 -> u8", || {
+        saturating_convert_slice(black_box(&src_f32[..]), &mut dst);
+        black_box(&mut dst);
+    });
+
+    #[cfg(feature = "portable-simd")]
+    {
+        use saturate::saturating_convert_slice_simd;
+
+        bench("saturating_convert_slice_simd i32 -> u8", || {
+            saturating_convert_slice_simd(black_box(&src_i32[..]), &mut dst);
+            black_box(&mut dst);
+        });
+        bench("saturating_convert_slice_simd f32 -> u8", || {
+            saturating_convert_slice_simd(black_box(&src_f32[..]), &mut dst);
+            black_box(&mut dst);
+        });
+    }
+}
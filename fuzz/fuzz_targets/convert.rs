@@ -0,0 +1,174 @@
+//! Converts an arbitrary value between an arbitrary pair of numeric types, checking that the
+//! conversion doesn't panic and that the result is consistent with saturation.
+//!
+//! The first two bytes of the input select the source and target types, and the remaining bytes
+//! are the little endian source value (zero padded if too short).
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use saturate::SaturatingFrom;
+
+trait Value: Copy + PartialEq + core::fmt::Debug {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+    const IS_FLOAT: bool;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    fn is_nan(self) -> bool;
+
+    /// Rounds toward zero, for float values
+    fn trunc(self) -> Self;
+}
+
+impl Value for bool {
+    const MIN: bool = false;
+    const MAX: bool = true;
+    const ZERO: bool = false;
+    const IS_FLOAT: bool = false;
+
+    fn from_le_bytes(bytes: &[u8]) -> bool {
+        bytes.first().is_some_and(|&byte| byte & 1 != 0)
+    }
+
+    fn is_nan(self) -> bool {
+        false
+    }
+
+    fn trunc(self) -> bool {
+        self
+    }
+}
+
+macro_rules! impl_value_int {
+    ($($typ:ty),+) => {
+        $(
+            impl Value for $typ {
+                const MIN: $typ = <$typ>::MIN;
+                const MAX: $typ = <$typ>::MAX;
+                const ZERO: $typ = 0;
+                const IS_FLOAT: bool = false;
+
+                fn from_le_bytes(bytes: &[u8]) -> $typ {
+                    let mut buf = [0; core::mem::size_of::<$typ>()];
+                    let len = buf.len().min(bytes.len());
+                    buf[..len].copy_from_slice(&bytes[..len]);
+                    <$typ>::from_le_bytes(buf)
+                }
+
+                fn is_nan(self) -> bool {
+                    false
+                }
+
+                fn trunc(self) -> $typ {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_value_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_value_float {
+    ($($typ:ty),+) => {
+        $(
+            impl Value for $typ {
+                const MIN: $typ = <$typ>::MIN;
+                const MAX: $typ = <$typ>::MAX;
+                const ZERO: $typ = 0.0;
+                const IS_FLOAT: bool = true;
+
+                fn from_le_bytes(bytes: &[u8]) -> $typ {
+                    let mut buf = [0; core::mem::size_of::<$typ>()];
+                    let len = buf.len().min(bytes.len());
+                    buf[..len].copy_from_slice(&bytes[..len]);
+                    <$typ>::from_le_bytes(buf)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$typ>::is_nan(self)
+                }
+
+                fn trunc(self) -> $typ {
+                    <$typ>::trunc(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_value_float!(f32, f64);
+
+fn check<Src, Dst>(bytes: &[u8])
+where
+    Src: Value + SaturatingFrom<Dst>,
+    Dst: Value + SaturatingFrom<Src>,
+{
+    let value = Src::from_le_bytes(bytes);
+    let result = Dst::saturating_from(value);
+
+    if Dst::IS_FLOAT {
+        // Float targets round rather than clamp, so only NaN can be checked exactly
+        assert_eq!(
+            value.is_nan(),
+            result.is_nan(),
+            "{:?} => {:?}",
+            value,
+            result
+        );
+    } else if value.is_nan() {
+        assert_eq!(Dst::ZERO, result, "NaN => {:?}", result);
+    } else if result != Dst::MIN && result != Dst::MAX {
+        // Any value which wasn't clamped must be converted exactly (after truncating floats)
+        let round_trip = Src::saturating_from(result);
+        assert_eq!(value.trunc(), round_trip, "{:?} => {:?}", value, result);
+    }
+
+    if <Dst as SaturatingFrom<Src>>::IS_WIDENING {
+        let round_trip = Src::saturating_from(result);
+        assert!(
+            round_trip == value || (value.is_nan() && round_trip.is_nan()),
+            "{:?} => {:?} is not lossless",
+            value,
+            result
+        );
+    }
+}
+
+macro_rules! dispatch {
+    ($src_tag:expr, $dst_tag:expr, $bytes:expr; $($typ:ty),+) => {
+        dispatch!(@src $src_tag, $dst_tag, $bytes; [$($typ),+]; $($typ),+)
+    };
+    (@src $src_tag:expr, $dst_tag:expr, $bytes:expr; $dsts:tt; $($src:ty),+) => {{
+        let mut tag = 0;
+        $(
+            if $src_tag == tag {
+                dispatch!(@dst $dst_tag, $bytes; $src; $dsts);
+            }
+            tag += 1;
+        )+
+        let _ = tag;
+    }};
+    (@dst $dst_tag:expr, $bytes:expr; $src:ty; [$($dst:ty),+]) => {{
+        let mut tag = 0;
+        $(
+            if $dst_tag == tag {
+                check::<$src, $dst>($bytes);
+            }
+            tag += 1;
+        )+
+        let _ = tag;
+    }};
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let [src, dst, bytes @ ..] = data {
+        dispatch!(
+            src % 15, dst % 15, bytes;
+            bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64
+        );
+    }
+});
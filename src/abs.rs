@@ -0,0 +1,70 @@
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion of the absolute value of a signed integer into an
+/// unsigned integer type.
+///
+/// Unlike calling [`abs`](i32::abs) before converting, this never overflows: the magnitude of
+/// `MIN` is computed using unsigned arithmetic, so it is one greater than `MAX`.
+///
+/// ```
+/// use saturate::SaturatingFromAbs;
+///
+/// assert_eq!(128, u8::saturating_from_abs(i8::MIN));
+/// assert_eq!(255, u8::saturating_from_abs(-300));
+/// assert_eq!(1u32 << 31, u32::saturating_from_abs(i32::MIN));
+/// ```
+pub trait SaturatingFromAbs<T>: SaturatingFrom<T> + Sized {
+    /// Converts the absolute value of the input type `T` to `Self`
+    fn saturating_from_abs(value: T) -> Self;
+}
+
+macro_rules! impl_abs {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromAbs<$src> for $dst {
+                #[inline]
+                fn saturating_from_abs(value: $src) -> $dst {
+                    <$dst>::saturating_from(value.unsigned_abs())
+                }
+            }
+        )+
+    };
+}
+
+impl_abs!([i8, i16, i32, i64, i128, isize] => u8);
+impl_abs!([i8, i16, i32, i64, i128, isize] => u16);
+impl_abs!([i8, i16, i32, i64, i128, isize] => u32);
+impl_abs!([i8, i16, i32, i64, i128, isize] => u64);
+impl_abs!([i8, i16, i32, i64, i128, isize] => u128);
+impl_abs!([i8, i16, i32, i64, i128, isize] => usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min() {
+        assert_eq!(128u8, u8::saturating_from_abs(i8::MIN));
+        assert_eq!(255u8, u8::saturating_from_abs(i16::MIN));
+        assert_eq!(1u32 << 31, u32::saturating_from_abs(i32::MIN));
+        assert_eq!(1u64 << 63, u64::saturating_from_abs(i64::MIN));
+        assert_eq!(1u128 << 127, u128::saturating_from_abs(i128::MIN));
+        assert_eq!(u16::MAX, u16::saturating_from_abs(isize::MIN));
+    }
+
+    #[test]
+    fn saturate() {
+        assert_eq!(255u8, u8::saturating_from_abs(-300i32));
+        assert_eq!(255u8, u8::saturating_from_abs(300i64));
+        assert_eq!(u16::MAX, u16::saturating_from_abs(i128::MAX));
+        assert_eq!(u32::MAX, u32::saturating_from_abs(-(1i64 << 40)));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(0u8, u8::saturating_from_abs(0i8));
+        assert_eq!(5u8, u8::saturating_from_abs(-5i64));
+        assert_eq!(127usize, usize::saturating_from_abs(i8::MAX));
+        assert_eq!(40000u128, u128::saturating_from_abs(-40000i32));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::classify::{Classify, Outcome};
+use crate::SaturatingFrom;
+
+/// The result of a saturating conversion, recording how the value was converted, see
+/// [`SaturatingFromAudited`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditResult<T> {
+    /// The value was converted exactly
+    Exact(T),
+    /// The value was in range but had a fractional part, which was truncated
+    Rounded(T),
+    /// The value was greater than the target's maximum, and was clamped to it
+    ClampedHigh(T),
+    /// The value was less than the target's minimum, and was clamped to it
+    ClampedLow(T),
+    /// The value was NaN, and was converted to zero
+    NanToZero(T),
+}
+
+impl<T> AuditResult<T> {
+    /// Returns the converted value, discarding how it was converted
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            AuditResult::Exact(value)
+            | AuditResult::Rounded(value)
+            | AuditResult::ClampedHigh(value)
+            | AuditResult::ClampedLow(value)
+            | AuditResult::NanToZero(value) => value,
+        }
+    }
+
+    /// Returns `true` if the value was converted exactly
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        matches!(self, AuditResult::Exact(_))
+    }
+}
+
+/// Trait to perform a saturating conversion to an integer type, also reporting exactly how the
+/// value was converted.
+///
+/// Only one variant of [`AuditResult`] is returned, even if more than one applies. Clamping takes
+/// precedence over rounding, so an out of range value with a fractional part (e.g. `300.5` into
+/// [`u8`]) is reported as [`ClampedHigh`](AuditResult::ClampedHigh).
+///
+/// ```
+/// use saturate::{AuditResult, SaturatingFromAudited};
+///
+/// assert_eq!(AuditResult::Exact(42), u8::saturating_from_audited(42));
+/// assert_eq!(AuditResult::Rounded(42), u8::saturating_from_audited(42.5));
+/// assert_eq!(AuditResult::ClampedHigh(255), u8::saturating_from_audited(300.5));
+/// assert_eq!(AuditResult::ClampedLow(0), u8::saturating_from_audited(-1));
+/// assert_eq!(AuditResult::NanToZero(0), u8::saturating_from_audited(f32::NAN));
+/// ```
+pub trait SaturatingFromAudited<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, recording how the value was converted
+    fn saturating_from_audited(value: T) -> AuditResult<Self>;
+}
+
+impl<T, U: Classify<T>> SaturatingFromAudited<T> for U {
+    #[inline]
+    fn saturating_from_audited(value: T) -> AuditResult<Self> {
+        match U::classify(value) {
+            (result, Outcome::Exact) => AuditResult::Exact(result),
+            (result, Outcome::Rounded) => AuditResult::Rounded(result),
+            (result, Outcome::Overflow) => AuditResult::ClampedHigh(result),
+            (result, Outcome::Underflow) => AuditResult::ClampedLow(result),
+            (result, Outcome::Nan) => AuditResult::NanToZero(result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            AuditResult::Exact(200u8),
+            u8::saturating_from_audited(200i32)
+        );
+        assert_eq!(AuditResult::Exact(1i8), i8::saturating_from_audited(true));
+        assert_eq!(
+            AuditResult::Exact(-7i16),
+            i16::saturating_from_audited(-7.0f64)
+        );
+        assert_eq!(
+            AuditResult::Rounded(2u8),
+            u8::saturating_from_audited(2.9f32)
+        );
+        assert_eq!(
+            AuditResult::Rounded(0u8),
+            u8::saturating_from_audited(-0.5f64)
+        );
+        assert_eq!(
+            AuditResult::ClampedHigh(i8::MAX),
+            i8::saturating_from_audited(u64::MAX)
+        );
+        assert_eq!(
+            AuditResult::ClampedLow(0u32),
+            u32::saturating_from_audited(i64::MIN)
+        );
+        assert_eq!(
+            AuditResult::NanToZero(0i64),
+            i64::saturating_from_audited(-f64::NAN)
+        );
+    }
+
+    #[test]
+    fn precedence() {
+        // Clamping takes precedence over rounding
+        assert_eq!(
+            AuditResult::ClampedHigh(255u8),
+            u8::saturating_from_audited(300.5f32)
+        );
+        assert_eq!(
+            AuditResult::ClampedLow(-128i8),
+            i8::saturating_from_audited(-129.5f64)
+        );
+        // Values which round into range are only rounded
+        assert_eq!(
+            AuditResult::Rounded(255u8),
+            u8::saturating_from_audited(255.5f32)
+        );
+        assert_eq!(
+            AuditResult::ClampedHigh(u64::MAX),
+            u64::saturating_from_audited(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn into_inner() {
+        assert_eq!(255u8, u8::saturating_from_audited(1e9f64).into_inner());
+        assert_eq!(0u8, u8::saturating_from_audited(f32::NAN).into_inner());
+        assert!(u8::saturating_from_audited(5u64).is_exact());
+        assert!(!u8::saturating_from_audited(5.5f64).is_exact());
+    }
+}
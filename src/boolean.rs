@@ -0,0 +1,184 @@
+use crate::SaturatingFrom;
+
+/// Trait to convert a numeric type to [`bool`] by comparing against a threshold.
+///
+/// This generalises the [`SaturatingFrom`] implementations for [`bool`], which are equivalent to
+/// a threshold just above zero. NaN always converts to `false`, consistent with those
+/// implementations, as does any value compared with a NaN threshold.
+///
+/// ```
+/// use saturate::SaturatingFromThreshold;
+///
+/// assert!(bool::saturating_from_threshold(0.5, 0.5));
+/// assert!(!bool::saturating_from_threshold(0.49, 0.5));
+/// assert!(bool::saturating_from_threshold(-3, -5));
+/// ```
+pub trait SaturatingFromThreshold<T>: SaturatingFrom<T> {
+    /// Returns `true` if `value` is greater than or equal to `threshold`
+    fn saturating_from_threshold(value: T, threshold: T) -> Self;
+}
+
+macro_rules! impl_threshold {
+    ($($src:ty),+) => {
+        $(
+            impl SaturatingFromThreshold<$src> for bool {
+                #[inline]
+                fn saturating_from_threshold(value: $src, threshold: $src) -> bool {
+                    value >= threshold
+                }
+            }
+        )+
+    };
+}
+
+impl_threshold!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64);
+
+/// Trait to convert a numeric type to [`bool`] using C-like truthiness, where any nonzero value is
+/// `true`.
+///
+/// The two conventions differ only for negative values:
+///
+/// | Value      | [`SaturatingFrom`] (`> 0`) | [`SaturatingFromNonzero`] (`!= 0`) |
+/// |------------|----------------------------|------------------------------------|
+/// | positive   | `true`                     | `true`                             |
+/// | zero       | `false`                    | `false`                            |
+/// | negative   | `false`                    | `true`                             |
+/// | NaN        | `false`                    | `false`                            |
+///
+/// ```
+/// use saturate::{SaturatingFrom, SaturatingFromNonzero};
+///
+/// assert!(bool::saturating_from_nonzero(-1.0));
+/// assert!(!bool::saturating_from(-1.0));
+/// assert!(!bool::saturating_from_nonzero(f64::NAN));
+/// ```
+pub trait SaturatingFromNonzero<T>: SaturatingFrom<T> {
+    /// Returns `true` if `value` is neither zero nor NaN
+    fn saturating_from_nonzero(value: T) -> Self;
+}
+
+macro_rules! impl_nonzero_int {
+    ($($src:ty),+) => {
+        $(
+            impl SaturatingFromNonzero<$src> for bool {
+                #[inline]
+                fn saturating_from_nonzero(value: $src) -> bool {
+                    value != 0
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_nonzero_float {
+    ($($src:ty),+) => {
+        $(
+            impl SaturatingFromNonzero<$src> for bool {
+                #[inline]
+                fn saturating_from_nonzero(value: $src) -> bool {
+                    // Both zeros compare equal to 0.0
+                    value != 0.0 && !value.is_nan()
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_float!(f32, f64);
+
+/// Converts a float to [`bool`] only if it is exactly `0.0` or `1.0`, the values [`bool`]
+/// converts to, so that round trips through a float are lossless.
+///
+/// Both `0.0` and `-0.0` return `Some(false)`. Any other value, including NaN, returns [`None`],
+/// whereas [`SaturatingFrom`] would convert it using `value > 0.0`.
+///
+/// ```
+/// use saturate::saturating_bool_from_exact;
+///
+/// assert_eq!(Some(true), saturating_bool_from_exact(1.0));
+/// assert_eq!(Some(false), saturating_bool_from_exact(0.0));
+/// assert_eq!(None, saturating_bool_from_exact(0.5));
+/// ```
+#[inline]
+pub fn saturating_bool_from_exact(value: f64) -> Option<bool> {
+    if value == 0.0 {
+        Some(false)
+    } else if value == 1.0 {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_float() {
+        assert!(bool::saturating_from_threshold(0.75f64, 0.5));
+        assert!(bool::saturating_from_threshold(0.5f64, 0.5));
+        assert!(!bool::saturating_from_threshold(0.25f64, 0.5));
+        assert!(bool::saturating_from_threshold(-0.5f32, -1.0));
+        assert!(!bool::saturating_from_threshold(-1.5f32, -1.0));
+        assert!(bool::saturating_from_threshold(f32::INFINITY, f32::MAX));
+        assert!(bool::saturating_from_threshold(-0.0f32, 0.0));
+
+        assert!(!bool::saturating_from_threshold(f64::NAN, 0.5));
+        assert!(!bool::saturating_from_threshold(
+            -f32::NAN,
+            f32::NEG_INFINITY
+        ));
+        assert!(!bool::saturating_from_threshold(1.0f64, f64::NAN));
+    }
+
+    #[test]
+    fn threshold_int() {
+        assert!(bool::saturating_from_threshold(512u16, 512));
+        assert!(!bool::saturating_from_threshold(511u16, 512));
+        assert!(bool::saturating_from_threshold(-3i32, -5));
+        assert!(!bool::saturating_from_threshold(-6i32, -5));
+        assert!(bool::saturating_from_threshold(i128::MIN, i128::MIN));
+    }
+
+    #[test]
+    fn nonzero_float() {
+        assert!(bool::saturating_from_nonzero(-1.0f64));
+        assert!(!bool::saturating_from(-1.0f64));
+        assert!(bool::saturating_from_nonzero(0.5f32));
+        assert!(bool::saturating_from_nonzero(f32::NEG_INFINITY));
+        assert!(bool::saturating_from_nonzero(f64::MIN_POSITIVE));
+        assert!(!bool::saturating_from_nonzero(0.0f64));
+        assert!(!bool::saturating_from_nonzero(-0.0f32));
+        assert!(!bool::saturating_from_nonzero(f64::NAN));
+        assert!(!bool::saturating_from_nonzero(-f32::NAN));
+    }
+
+    #[test]
+    fn nonzero_int() {
+        assert!(bool::saturating_from_nonzero(-1i32));
+        assert!(!bool::saturating_from(-1i32));
+        assert!(bool::saturating_from_nonzero(i128::MIN));
+        assert!(bool::saturating_from_nonzero(1u8));
+        assert!(!bool::saturating_from_nonzero(0usize));
+    }
+
+    #[test]
+    fn bool_from_exact() {
+        assert_eq!(Some(false), saturating_bool_from_exact(0.0));
+        assert_eq!(Some(false), saturating_bool_from_exact(-0.0));
+        assert_eq!(Some(true), saturating_bool_from_exact(1.0));
+        assert_eq!(None, saturating_bool_from_exact(0.5));
+        assert_eq!(None, saturating_bool_from_exact(-1.0));
+        assert_eq!(None, saturating_bool_from_exact(1.0 + f64::EPSILON));
+        assert_eq!(None, saturating_bool_from_exact(f64::INFINITY));
+        assert_eq!(None, saturating_bool_from_exact(f64::NAN));
+
+        for &value in &[false, true] {
+            let float = f64::saturating_from(value);
+            assert_eq!(Some(value), saturating_bool_from_exact(float));
+        }
+    }
+}
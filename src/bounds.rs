@@ -0,0 +1,114 @@
+/// Trait providing the bounds that saturating conversions clamp to.
+///
+/// For integers these are `MIN` and `MAX`, and for [`bool`] they are `false` and `true`. For
+/// floating point types they are [`NEG_INFINITY`](f64::NEG_INFINITY) and
+/// [`INFINITY`](f64::INFINITY) rather than the finite `MIN` and `MAX`, since conversions to
+/// floats round values beyond the finite range to infinity (e.g. `f32::saturating_from(u128::MAX)`).
+///
+/// ```
+/// use saturate::{SaturatingBounds, SaturatingFrom};
+///
+/// fn is_saturated<T: SaturatingBounds + PartialEq>(value: T) -> bool {
+///     value == T::saturating_min() || value == T::saturating_max()
+/// }
+///
+/// assert!(is_saturated(u8::saturating_from(300)));
+/// assert!(!is_saturated(i16::saturating_from(300)));
+/// assert!(is_saturated(f32::saturating_from(u128::MAX)));
+/// ```
+pub trait SaturatingBounds {
+    /// Returns the smallest value of `Self`
+    fn saturating_min() -> Self;
+
+    /// Returns the largest value of `Self`
+    fn saturating_max() -> Self;
+}
+
+macro_rules! impl_bounds_int {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingBounds for $typ {
+                #[inline]
+                fn saturating_min() -> $typ {
+                    <$typ>::MIN
+                }
+
+                #[inline]
+                fn saturating_max() -> $typ {
+                    <$typ>::MAX
+                }
+            }
+        )+
+    };
+}
+
+impl_bounds_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_bounds_float {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingBounds for $typ {
+                #[inline]
+                fn saturating_min() -> $typ {
+                    <$typ>::NEG_INFINITY
+                }
+
+                #[inline]
+                fn saturating_max() -> $typ {
+                    <$typ>::INFINITY
+                }
+            }
+        )+
+    };
+}
+
+impl_bounds_float!(f32, f64);
+
+impl SaturatingBounds for bool {
+    #[inline]
+    fn saturating_min() -> bool {
+        false
+    }
+
+    #[inline]
+    fn saturating_max() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingFrom;
+
+    #[test]
+    fn bounds_int() {
+        macro_rules! check_bounds {
+            ($($typ:ty),+) => {
+                $(
+                    assert_eq!(<$typ>::MIN, <$typ>::saturating_min());
+                    assert_eq!(<$typ>::MAX, <$typ>::saturating_max());
+                    assert_eq!(<$typ>::saturating_max(), <$typ>::saturating_from(f64::INFINITY));
+                    assert_eq!(<$typ>::saturating_min(), <$typ>::saturating_from(f64::NEG_INFINITY));
+                )+
+            };
+        }
+
+        check_bounds!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+    }
+
+    #[test]
+    fn bounds_float() {
+        assert_eq!(f32::INFINITY, f32::saturating_max());
+        assert_eq!(f32::NEG_INFINITY, f32::saturating_min());
+        assert_eq!(f64::INFINITY, f64::saturating_max());
+        assert_eq!(f64::NEG_INFINITY, f64::saturating_min());
+        assert_eq!(f32::saturating_max(), f32::saturating_from(f64::MAX));
+    }
+
+    #[test]
+    fn bounds_bool() {
+        assert!(!bool::saturating_min());
+        assert!(bool::saturating_max());
+    }
+}
@@ -0,0 +1,209 @@
+use crate::error::DecodeError;
+use crate::SaturatingFrom;
+
+struct Width<const N: usize>;
+
+impl<const N: usize> Width<N> {
+    const CHECK: () = assert!(
+        matches!(N, 1 | 2 | 4 | 8 | 16),
+        "byte arrays must be 1, 2, 4, 8 or 16 bytes long"
+    );
+}
+
+/// Trait to perform a saturating conversion from an unsigned integer stored as a byte array.
+///
+/// Arrays of 1, 2, 4, 8 or 16 bytes are supported; using any other length is a compile time error.
+///
+/// The bytes are always read as an unsigned integer, so signed targets never decode a negative
+/// value: a set sign bit is treated as a large magnitude and saturates to `Self::MAX`.
+///
+/// ```
+/// use saturate::SaturatingFromBytes;
+///
+/// assert_eq!(65535, u16::saturating_from_be_bytes([0, 0, 0, 0, 0, 1, 0, 0]));
+/// assert_eq!(258, i32::saturating_from_le_bytes([2, 1]));
+/// assert_eq!(i32::MAX, i32::saturating_from_be_bytes([0xff; 4]));
+/// ```
+///
+/// ```compile_fail
+/// use saturate::SaturatingFromBytes;
+///
+/// let _ = u8::saturating_from_be_bytes([0; 3]);
+/// ```
+pub trait SaturatingFromBytes: SaturatingFrom<u128> + Sized {
+    /// Converts an unsigned integer from its big endian byte representation to `Self`
+    #[inline]
+    fn saturating_from_be_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        let () = Width::<N>::CHECK;
+        let mut buf = [0; 16];
+        buf[16 - N..].copy_from_slice(&bytes);
+        Self::saturating_from(u128::from_be_bytes(buf))
+    }
+
+    /// Converts an unsigned integer from its little endian byte representation to `Self`
+    #[inline]
+    fn saturating_from_le_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        let () = Width::<N>::CHECK;
+        let mut buf = [0; 16];
+        buf[..N].copy_from_slice(&bytes);
+        Self::saturating_from(u128::from_le_bytes(buf))
+    }
+}
+
+macro_rules! impl_bytes {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingFromBytes for $typ {}
+        )+
+    };
+}
+
+impl_bytes!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+// Splits `bytes` into unsigned integers of `width` bytes and converts each to `Dst`
+fn decode_slice<Dst>(bytes: &[u8], width: usize, big_endian: bool) -> Result<Vec<Dst>, DecodeError>
+where
+    Dst: SaturatingFrom<u128>,
+{
+    if width == 0 || width > 16 {
+        return Err(DecodeError::InvalidWidth);
+    }
+    if bytes.len() % width != 0 {
+        return Err(DecodeError::InvalidLength);
+    }
+    let read = |acc: u128, &byte: &u8| acc << 8 | u128::from(byte);
+    let values = bytes.chunks_exact(width).map(|chunk| {
+        if big_endian {
+            chunk.iter().fold(0, read)
+        } else {
+            chunk.iter().rev().fold(0, read)
+        }
+    });
+    Ok(values.map(Dst::saturating_from).collect())
+}
+
+/// Decodes a buffer of big endian unsigned integers of `src_width` bytes each, converting each
+/// one to `Dst`.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::InvalidWidth`] if `src_width` is zero or greater than 16, or
+/// [`DecodeError::InvalidLength`] if the length of `bytes` is not a multiple of `src_width`.
+///
+/// ```
+/// use saturate::saturating_from_be_slice;
+///
+/// let bytes = [0x00, 0x00, 0x00, 0x2a, 0x00, 0x01, 0x00, 0x00];
+/// assert_eq!(Ok(vec![42u8, 255]), saturating_from_be_slice(&bytes, 4));
+/// ```
+pub fn saturating_from_be_slice<Dst>(
+    bytes: &[u8],
+    src_width: usize,
+) -> Result<Vec<Dst>, DecodeError>
+where
+    Dst: SaturatingFrom<u128>,
+{
+    decode_slice(bytes, src_width, true)
+}
+
+/// Decodes a buffer of little endian unsigned integers of `src_width` bytes each, converting
+/// each one to `Dst`.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::InvalidWidth`] if `src_width` is zero or greater than 16, or
+/// [`DecodeError::InvalidLength`] if the length of `bytes` is not a multiple of `src_width`.
+///
+/// ```
+/// use saturate::saturating_from_le_slice;
+///
+/// let bytes = [0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00];
+/// assert_eq!(Ok(vec![42u8, 255]), saturating_from_le_slice(&bytes, 4));
+/// ```
+pub fn saturating_from_le_slice<Dst>(
+    bytes: &[u8],
+    src_width: usize,
+) -> Result<Vec<Dst>, DecodeError>
+where
+    Dst: SaturatingFrom<u128>,
+{
+    decode_slice(bytes, src_width, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_bytes() {
+        let value = 0x0102_0304_0506_0708u64;
+        assert_eq!(65535u16, u16::saturating_from_be_bytes(value.to_be_bytes()));
+        assert_eq!(65535u16, u16::saturating_from_le_bytes(value.to_le_bytes()));
+        assert_eq!(value, u64::saturating_from_be_bytes(value.to_be_bytes()));
+        assert_eq!(value, u64::saturating_from_le_bytes(value.to_le_bytes()));
+
+        assert_eq!(0x7fi8, i8::saturating_from_be_bytes([0xff]));
+        assert_eq!(0x1234i16, i16::saturating_from_be_bytes([0x12, 0x34]));
+        assert_eq!(0x3412i32, i32::saturating_from_le_bytes([0x12, 0x34]));
+        assert_eq!(u128::MAX, u128::saturating_from_le_bytes([0xff; 16]));
+        assert_eq!(i128::MAX, i128::saturating_from_be_bytes([0xff; 16]));
+        assert_eq!(i32::MAX, i32::saturating_from_be_bytes([0xff; 4]));
+        assert_eq!(i16::MAX, i16::saturating_from_le_bytes([0, 0x80]));
+        assert_eq!(
+            0x12345678u32,
+            u32::saturating_from_be_bytes([0x12, 0x34, 0x56, 0x78])
+        );
+    }
+
+    #[test]
+    fn decode_slice() {
+        let values = [42u32, 255, 256, 70000, u32::MAX];
+        let be: Vec<u8> = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+        let le: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let expected = vec![42u8, 255, 255, 255, 255];
+        assert_eq!(Ok(expected.clone()), saturating_from_be_slice(&be, 4));
+        assert_eq!(Ok(expected), saturating_from_le_slice(&le, 4));
+        assert_eq!(Ok(values.to_vec()), saturating_from_be_slice(&be, 4));
+        assert_eq!(Ok(values.to_vec()), saturating_from_le_slice(&le, 4));
+
+        let bytes = [0x01, 0x02, 0x03, 0x00, 0x00, 0x7f];
+        assert_eq!(
+            Ok(vec![0x010203i32, 0x7f]),
+            saturating_from_be_slice(&bytes, 3)
+        );
+        assert_eq!(
+            Ok(vec![i16::MAX, 0x7f]),
+            saturating_from_be_slice(&bytes, 3)
+        );
+        assert_eq!(
+            Ok(vec![0x030201i32, 0x7f0000]),
+            saturating_from_le_slice(&bytes, 3)
+        );
+        assert_eq!(Ok(vec![0x0201u16]), saturating_from_le_slice(&[1, 2], 2));
+        assert_eq!(
+            Ok(vec![u128::MAX]),
+            saturating_from_be_slice(&[0xff; 16], 16)
+        );
+        assert_eq!(Ok(Vec::<u8>::new()), saturating_from_be_slice(&[], 2));
+    }
+
+    #[test]
+    fn decode_slice_error() {
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            saturating_from_be_slice::<u8>(&[0; 7], 4)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            saturating_from_le_slice::<u8>(&[0; 1], 2)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidWidth),
+            saturating_from_be_slice::<u8>(&[0; 4], 0)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidWidth),
+            saturating_from_le_slice::<u8>(&[0; 17], 17)
+        );
+    }
+}
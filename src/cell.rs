@@ -0,0 +1,84 @@
+use core::cell::{Cell, RefCell};
+
+use crate::SaturatingFrom;
+
+/// Copies the value out of `cell` and converts it to `Dst`.
+///
+/// ```
+/// use std::cell::Cell;
+/// use saturate::saturating_from_cell;
+///
+/// let register = Cell::new(-40i64);
+/// assert_eq!(0u8, saturating_from_cell(&register));
+/// ```
+#[inline]
+pub fn saturating_from_cell<Dst, Src>(cell: &Cell<Src>) -> Dst
+where
+    Src: Copy,
+    Dst: SaturatingFrom<Src>,
+{
+    Dst::saturating_from(cell.get())
+}
+
+/// Borrows the value in `cell`, copies it, and converts it to `Dst`.
+///
+/// The borrow is released before this function returns.
+///
+/// # Panics
+///
+/// Panics if `cell` is currently mutably borrowed.
+///
+/// ```
+/// use std::cell::RefCell;
+/// use saturate::saturating_from_ref_cell;
+///
+/// let register = RefCell::new(1e10f64);
+/// assert_eq!(i32::MAX, saturating_from_ref_cell(&register));
+/// ```
+#[inline]
+pub fn saturating_from_ref_cell<Dst, Src>(cell: &RefCell<Src>) -> Dst
+where
+    Src: Copy,
+    Dst: SaturatingFrom<Src>,
+{
+    Dst::saturating_from(*cell.borrow())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_cell() {
+        let cell = Cell::new(1000i64);
+        assert_eq!(255u8, saturating_from_cell(&cell));
+        assert_eq!(1000u16, saturating_from_cell(&cell));
+        cell.set(-1);
+        assert_eq!(0u8, saturating_from_cell(&cell));
+        assert_eq!(-1i8, saturating_from_cell(&cell));
+        assert_eq!(-1, cell.get());
+    }
+
+    #[test]
+    fn from_ref_cell() {
+        let cell = RefCell::new(f32::NAN);
+        assert_eq!(0u32, saturating_from_ref_cell(&cell));
+        *cell.borrow_mut() = -3.7;
+        assert_eq!(-3i64, saturating_from_ref_cell(&cell));
+
+        // Shared borrows can coexist, and the borrow is released afterwards
+        let shared = cell.borrow();
+        assert_eq!(0u8, saturating_from_ref_cell(&cell));
+        drop(shared);
+        *cell.borrow_mut() = 1e10;
+        assert_eq!(u16::MAX, saturating_from_ref_cell(&cell));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_ref_cell_mutably_borrowed() {
+        let cell = RefCell::new(5u64);
+        let _guard = cell.borrow_mut();
+        let _: u8 = saturating_from_ref_cell(&cell);
+    }
+}
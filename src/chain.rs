@@ -0,0 +1,72 @@
+use crate::SaturatingFrom;
+
+/// Builder for conversions which deliberately pass through intermediate types.
+///
+/// Each step saturates in turn, which can differ from converting directly. For example, converting
+/// `-1.5f64` to [`u8`] via [`i8`] truncates to `-1` before clamping to `0`, and converting `300`
+/// to [`u16`] via [`u8`] clamps to `255` along the way.
+///
+/// ```
+/// use saturate::Saturate;
+///
+/// assert_eq!(2u8, Saturate::new(2.7f64).via::<i32>().into::<u8>());
+/// assert_eq!(255u16, Saturate::new(300i32).via::<u8>().into::<u16>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use = "the value is only converted by calling `into`"]
+pub struct Saturate<T>(T);
+
+impl<T> Saturate<T> {
+    /// Starts a conversion from `value`
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Saturate(value)
+    }
+
+    /// Converts the current value to the intermediate type `U`
+    #[inline]
+    pub fn via<U: SaturatingFrom<T>>(self) -> Saturate<U> {
+        Saturate(U::saturating_from(self.0))
+    }
+
+    /// Converts the current value to the final type `U`
+    #[inline]
+    pub fn into<U: SaturatingFrom<T>>(self) -> U {
+        U::saturating_from(self.0)
+    }
+
+    /// Returns the current value without converting it
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain() {
+        assert_eq!(2u8, Saturate::new(2.7f64).via::<i32>().into::<u8>());
+        assert_eq!(0u8, Saturate::new(-1.5f64).via::<i8>().into::<u8>());
+        assert_eq!(255u16, Saturate::new(300i32).via::<u8>().into::<u16>());
+        assert_eq!(
+            127i64,
+            Saturate::new(u128::MAX)
+                .via::<u64>()
+                .via::<i8>()
+                .into::<i64>()
+        );
+        assert_eq!(0i32, Saturate::new(f32::NAN).via::<f64>().into::<i32>());
+    }
+
+    #[test]
+    fn direct_differs() {
+        // Without the intermediate step nothing is clamped or truncated along the way
+        assert_eq!(300u16, Saturate::new(300i32).into::<u16>());
+        assert_eq!(3.9f32, Saturate::new(3.9f64).into::<f32>());
+        assert_eq!(3.0f32, Saturate::new(3.9f64).via::<u8>().into::<f32>());
+        assert_eq!(3.9f64, Saturate::new(3.9f64).into_inner());
+    }
+}
@@ -0,0 +1,43 @@
+//! Saturating conversions from integers to [`char`].
+//!
+//! [`char`] is not a numeric type, so it is not covered by [`SaturatingFrom`](crate::SaturatingFrom).
+//! Unlike the numeric types, the valid values of [`char`] are not contiguous: the surrogate code
+//! points `0xD800..=0xDFFF` are not Unicode scalar values. The functions in this module handle
+//! that gap explicitly. Wider integer types can be converted by first converting to [`u32`] with
+//! [`SaturatingFrom`](crate::SaturatingFrom).
+
+/// Converts a code point to a [`char`], saturating values greater than [`char::MAX`].
+///
+/// Surrogate code points saturate to `'\u{D7FF}'`, the greatest scalar value below the gap.
+///
+/// ```
+/// use saturate::char::saturating_char_from_u32;
+///
+/// assert_eq!('A', saturating_char_from_u32(0x41));
+/// assert_eq!('\u{D7FF}', saturating_char_from_u32(0xD800));
+/// assert_eq!(char::MAX, saturating_char_from_u32(u32::MAX));
+/// ```
+#[inline]
+pub fn saturating_char_from_u32(value: u32) -> char {
+    match value {
+        0xD800..=0xDFFF => '\u{D7FF}',
+        _ => core::char::from_u32(value).unwrap_or(char::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_from_u32() {
+        assert_eq!('\0', saturating_char_from_u32(0));
+        assert_eq!('\u{D7FF}', saturating_char_from_u32(0xD7FF));
+        assert_eq!('\u{D7FF}', saturating_char_from_u32(0xD800));
+        assert_eq!('\u{D7FF}', saturating_char_from_u32(0xDFFF));
+        assert_eq!('\u{E000}', saturating_char_from_u32(0xE000));
+        assert_eq!(char::MAX, saturating_char_from_u32(0x10FFFF));
+        assert_eq!(char::MAX, saturating_char_from_u32(0x110000));
+        assert_eq!(char::MAX, saturating_char_from_u32(u32::MAX));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::SaturatingFrom;
+
+/// Trait to clamp a value to a range within the same type, with predictable NaN handling.
+///
+/// For floating point types a NaN value clamps to `min`, consistent with NaN converting to zero
+/// (the bottom of the range for unsigned targets) in [`SaturatingFrom`].
+/// Unlike [`f64::clamp`], this never panics in release builds; a NaN bound is ignored.
+///
+/// ```
+/// use saturate::SaturatingClamp;
+///
+/// assert_eq!(10, 42.saturating_clamp(0, 10));
+/// assert_eq!(1.0, 1e10.saturating_clamp(-1.0, 1.0));
+/// assert_eq!(-1.0, f64::NAN.saturating_clamp(-1.0, 1.0));
+/// ```
+///
+/// # Panics
+///
+/// Debug builds panic if `min > max`, or if either bound is NaN.
+pub trait SaturatingClamp: Sized {
+    /// Restricts `self` to the range `min..=max`
+    fn saturating_clamp(self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_clamp_int {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingClamp for $typ {
+                #[inline]
+                fn saturating_clamp(self, min: $typ, max: $typ) -> $typ {
+                    debug_assert!(min <= max, "min must be less than or equal to max");
+                    self.max(min).min(max)
+                }
+            }
+        )+
+    };
+}
+
+impl_clamp_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_clamp_float {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingClamp for $typ {
+                #[inline]
+                fn saturating_clamp(self, min: $typ, max: $typ) -> $typ {
+                    debug_assert!(min <= max, "min must be less than or equal to max");
+                    if self.is_nan() {
+                        min
+                    } else {
+                        self.max(min).min(max)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_clamp_float!(f32, f64);
+
+/// Trait to perform a saturating conversion followed by a clamp to a range only known at runtime,
+/// such as one declared in a configuration schema.
+///
+/// ```
+/// use saturate::SaturatingFromValidated;
+///
+/// assert_eq!((80, true), u16::saturating_from_validated(80i64, 1, 1024));
+/// assert_eq!((1024, false), u16::saturating_from_validated(8080i64, 1, 1024));
+/// ```
+pub trait SaturatingFromValidated<T>: SaturatingFrom<T> + SaturatingClamp {
+    /// Converts the input type `T` to `Self` and restricts the result to the range `min..=max`,
+    /// also returning whether the converted value was already within that range.
+    ///
+    /// The range is checked after the type conversion, so a value which saturates to the bound
+    /// of `Self` is in range if that bound is. NaN is never in range and clamps to `min`, as
+    /// with [`SaturatingClamp`].
+    ///
+    /// # Panics
+    ///
+    /// Debug builds panic if `min > max`, or if either bound is NaN.
+    fn saturating_from_validated(value: T, min: Self, max: Self) -> (Self, bool);
+}
+
+impl<T, U> SaturatingFromValidated<T> for U
+where
+    U: SaturatingFrom<T> + SaturatingClamp + PartialOrd + Copy,
+{
+    #[inline]
+    fn saturating_from_validated(value: T, min: U, max: U) -> (U, bool) {
+        let converted = U::saturating_from(value);
+        let in_range = min <= converted && converted <= max;
+        (converted.saturating_clamp(min, max), in_range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_int() {
+        assert_eq!(5u8, 5u8.saturating_clamp(0, 10));
+        assert_eq!(10u8, 255u8.saturating_clamp(0, 10));
+        assert_eq!(-3i32, i32::MIN.saturating_clamp(-3, 3));
+        assert_eq!(3i32, i32::MAX.saturating_clamp(-3, 3));
+        assert_eq!(7i64, 0i64.saturating_clamp(7, 7));
+        assert_eq!(u128::MAX, u128::MAX.saturating_clamp(0, u128::MAX));
+    }
+
+    #[test]
+    fn clamp_float() {
+        assert_eq!(0.5f64, 0.5f64.saturating_clamp(0.0, 1.0));
+        assert_eq!(1.0f64, 1e300f64.saturating_clamp(0.0, 1.0));
+        assert_eq!(0.0f32, f32::NEG_INFINITY.saturating_clamp(0.0, 1.0));
+        assert_eq!(1.0f32, f32::INFINITY.saturating_clamp(0.0, 1.0));
+        assert_eq!(-0.0f64, (-0.0f64).saturating_clamp(-1.0, 1.0));
+        assert_eq!(f64::MAX, f64::INFINITY.saturating_clamp(f64::MIN, f64::MAX));
+    }
+
+    #[test]
+    fn clamp_nan() {
+        assert_eq!(-1.0f64, f64::NAN.saturating_clamp(-1.0, 1.0));
+        assert_eq!(0.0f32, f32::NAN.saturating_clamp(0.0, 255.0));
+        assert_eq!(
+            f64::NEG_INFINITY,
+            f64::NAN.saturating_clamp(f64::NEG_INFINITY, f64::INFINITY)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn clamp_invalid_range() {
+        let _ = 5i32.saturating_clamp(10, 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn clamp_nan_bound() {
+        let _ = 5.0f64.saturating_clamp(f64::NAN, 1.0);
+    }
+
+    #[test]
+    fn validated() {
+        assert_eq!((5u8, true), u8::saturating_from_validated(5i32, 0, 10));
+        assert_eq!((10u8, false), u8::saturating_from_validated(200i32, 0, 10));
+        assert_eq!((1u8, false), u8::saturating_from_validated(-1i32, 1, 10));
+        assert_eq!((255u8, true), u8::saturating_from_validated(300i32, 0, 255));
+        assert_eq!((10u8, true), u8::saturating_from_validated(10.4f64, 10, 20));
+        assert_eq!(
+            (0.5f32, false),
+            f32::saturating_from_validated(1e300f64, -0.5, 0.5)
+        );
+        assert_eq!(
+            (-0.5f32, false),
+            f32::saturating_from_validated(f64::NAN, -0.5, 0.5)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use core::cmp::Ordering;
+
+use crate::SaturatingFrom;
+
+/// What happened to a value during a saturating conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    /// The value was converted exactly
+    Exact,
+    /// The value was in range but had to be rounded
+    Rounded,
+    /// The value was greater than the target's maximum
+    Overflow,
+    /// The value was less than the target's minimum
+    Underflow,
+    /// The value was NaN
+    Nan,
+}
+
+/// Saturating conversion which also reports what happened to the value
+pub(crate) trait Classify<T>: SaturatingFrom<T> + Sized {
+    fn classify(value: T) -> (Self, Outcome);
+}
+
+macro_rules! impl_classify_int {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl Classify<$src> for $dst {
+                #[inline]
+                fn classify(value: $src) -> ($dst, Outcome) {
+                    let result = <$dst>::saturating_from(value);
+                    // Converting back is always exact since `result` is within both types' ranges
+                    let outcome = match value.cmp(&<$src>::saturating_from(result)) {
+                        Ordering::Less => Outcome::Underflow,
+                        Ordering::Equal => Outcome::Exact,
+                        Ordering::Greater => Outcome::Overflow,
+                    };
+                    (result, outcome)
+                }
+            }
+        )+
+    };
+}
+
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u8);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u16);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u32);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u64);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u128);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => usize);
+
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i8);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i16);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i32);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i64);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i128);
+impl_classify_int!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => isize);
+
+macro_rules! impl_classify_float {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl Classify<$src> for $dst {
+                #[inline]
+                fn classify(value: $src) -> ($dst, Outcome) {
+                    // MAX + 1 and MIN are powers of two (or zero) so are exact as floats. The
+                    // subtraction is exact whenever the result is small enough to matter
+                    let max = (<$dst>::MAX / 2 + 1) as $src * 2.0;
+                    let min = <$dst>::MIN as $src;
+
+                    let result = <$dst>::saturating_from(value);
+                    let outcome = if value.is_nan() {
+                        Outcome::Nan
+                    } else if value >= max {
+                        Outcome::Overflow
+                    } else if value - min <= -1.0 {
+                        Outcome::Underflow
+                    } else if value != <$src>::saturating_from(result) {
+                        Outcome::Rounded
+                    } else {
+                        Outcome::Exact
+                    };
+                    (result, outcome)
+                }
+            }
+        )+
+    };
+}
+
+impl_classify_float!([f32, f64] => u8);
+impl_classify_float!([f32, f64] => u16);
+impl_classify_float!([f32, f64] => u32);
+impl_classify_float!([f32, f64] => u64);
+impl_classify_float!([f32, f64] => u128);
+impl_classify_float!([f32, f64] => usize);
+
+impl_classify_float!([f32, f64] => i8);
+impl_classify_float!([f32, f64] => i16);
+impl_classify_float!([f32, f64] => i32);
+impl_classify_float!([f32, f64] => i64);
+impl_classify_float!([f32, f64] => i128);
+impl_classify_float!([f32, f64] => isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_classify_int() {
+        assert_eq!((255u8, Outcome::Overflow), u8::classify(300i32));
+        assert_eq!((0u8, Outcome::Underflow), u8::classify(-1i8));
+        assert_eq!((200u8, Outcome::Exact), u8::classify(200u128));
+        assert_eq!((1i8, Outcome::Exact), i8::classify(true));
+        assert_eq!((i64::MAX, Outcome::Overflow), i64::classify(u64::MAX));
+        assert_eq!((i128::MIN, Outcome::Exact), i128::classify(i128::MIN));
+        assert_eq!((0usize, Outcome::Underflow), usize::classify(isize::MIN));
+        assert_eq!((u128::MAX, Outcome::Exact), u128::classify(u128::MAX));
+    }
+
+    #[test]
+    fn impl_classify_float() {
+        assert_eq!((0u8, Outcome::Nan), u8::classify(f32::NAN));
+        assert_eq!((0i64, Outcome::Nan), i64::classify(-f64::NAN));
+        assert_eq!((255u8, Outcome::Exact), u8::classify(255.0f32));
+        assert_eq!((255u8, Outcome::Rounded), u8::classify(255.9f32));
+        assert_eq!((255u8, Outcome::Overflow), u8::classify(256.0f64));
+        assert_eq!((0u8, Outcome::Rounded), u8::classify(-0.9f64));
+        assert_eq!((0u8, Outcome::Exact), u8::classify(-0.0f64));
+        assert_eq!((0u8, Outcome::Underflow), u8::classify(-1.0f64));
+        assert_eq!((-128i8, Outcome::Rounded), i8::classify(-128.5f32));
+        assert_eq!((-128i8, Outcome::Underflow), i8::classify(-129.0f32));
+        assert_eq!((127i8, Outcome::Overflow), i8::classify(128.0f32));
+
+        // Bounds that are not exactly representable as floats
+        assert_eq!(
+            (u64::MAX, Outcome::Overflow),
+            u64::classify(u64::MAX as f32)
+        );
+        assert_eq!(
+            (i64::MAX, Outcome::Overflow),
+            i64::classify(i64::MAX as f64)
+        );
+        assert_eq!((i64::MIN, Outcome::Exact), i64::classify(i64::MIN as f64));
+        assert_eq!(
+            (i64::MIN, Outcome::Underflow),
+            i64::classify(-9.223373e18f32)
+        );
+        assert_eq!(
+            (i32::MIN, Outcome::Rounded),
+            i32::classify(-2147483648.5f64)
+        );
+        assert_eq!(
+            (i32::MIN, Outcome::Underflow),
+            i32::classify(-2147483649.0f64)
+        );
+        assert_eq!((f32::MAX as u128, Outcome::Exact), u128::classify(f32::MAX));
+        assert_eq!(
+            (u128::MAX, Outcome::Overflow),
+            u128::classify(f32::INFINITY)
+        );
+        assert_eq!(
+            (0u128, Outcome::Underflow),
+            u128::classify(f32::NEG_INFINITY)
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use core::ops::{Range, RangeInclusive};
+
+use crate::{SaturatingBounds, SaturatingFrom};
+
+/// Converts the [`Ok`] value, passing any [`Err`] through unchanged
+impl<Src, Dst, E> SaturatingFrom<Result<Src, E>> for Result<Dst, E>
+where
+    Dst: SaturatingFrom<Src>,
+{
+    const IS_WIDENING: bool = Dst::IS_WIDENING;
+
+    #[inline]
+    fn saturating_from(value: Result<Src, E>) -> Self {
+        value.map(Dst::saturating_from)
+    }
+}
+
+/// Converts each element
+impl<Src, Dst, const N: usize> SaturatingFrom<[Src; N]> for [Dst; N]
+where
+    Dst: SaturatingFrom<Src>,
+{
+    const IS_WIDENING: bool = Dst::IS_WIDENING;
+
+    #[inline]
+    fn saturating_from(value: [Src; N]) -> Self {
+        value.map(Dst::saturating_from)
+    }
+}
+
+/// Converts each element. This allocates a new buffer unless `Src` and `Dst` have the same size
+/// and alignment, in which case the standard library may reuse the source allocation
+impl<Src, Dst> SaturatingFrom<Vec<Src>> for Vec<Dst>
+where
+    Dst: SaturatingFrom<Src>,
+{
+    const IS_WIDENING: bool = Dst::IS_WIDENING;
+
+    #[inline]
+    fn saturating_from(value: Vec<Src>) -> Self {
+        value.into_iter().map(Dst::saturating_from).collect()
+    }
+}
+
+/// Converts both bounds. A resulting range with `start > end` is replaced by the empty range
+/// `start..start`
+impl<Src, Dst> SaturatingFrom<Range<Src>> for Range<Dst>
+where
+    Dst: SaturatingFrom<Src> + PartialOrd + Clone,
+{
+    const IS_WIDENING: bool = Dst::IS_WIDENING;
+
+    #[inline]
+    fn saturating_from(value: Range<Src>) -> Self {
+        let start = Dst::saturating_from(value.start);
+        let end = Dst::saturating_from(value.end);
+        if start > end {
+            start.clone()..start
+        } else {
+            start..end
+        }
+    }
+}
+
+/// Converts both bounds. An empty range, including one exhausted by iteration, converts to an
+/// empty range: the converted bounds if `start > end` still holds, otherwise
+/// `saturating_max()..=saturating_min()`, since clamping can make both bounds equal
+impl<Src, Dst> SaturatingFrom<RangeInclusive<Src>> for RangeInclusive<Dst>
+where
+    Src: PartialOrd,
+    Dst: SaturatingFrom<Src> + SaturatingBounds + PartialOrd,
+{
+    const IS_WIDENING: bool = Dst::IS_WIDENING;
+
+    #[inline]
+    fn saturating_from(value: RangeInclusive<Src>) -> Self {
+        let is_empty = value.is_empty();
+        let (start, end) = value.into_inner();
+        let start = Dst::saturating_from(start);
+        let end = Dst::saturating_from(end);
+        if is_empty && start <= end {
+            Dst::saturating_max()..=Dst::saturating_min()
+        } else {
+            start..=end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingInto;
+
+    #[test]
+    fn impl_result() {
+        assert_eq!(
+            Ok::<u8, ()>(255),
+            Result::saturating_from(Ok::<i32, ()>(300))
+        );
+        assert_eq!(
+            Ok::<i8, &str>(-128),
+            Ok::<f64, &str>(-1e10).saturating_into()
+        );
+        assert_eq!(
+            Err::<u8, &str>("parse error"),
+            Result::saturating_from(Err::<i32, _>("parse error"))
+        );
+    }
+
+    #[test]
+    fn impl_array() {
+        assert_eq!(
+            [0u8, 76, 255],
+            <[u8; 3]>::saturating_from([-26i32, 76, 935])
+        );
+        assert_eq!([0i8; 0], <[i8; 0]>::saturating_from([0u64; 0]));
+
+        let rgba: [u8; 4] = [1.5f32, -1.0, 300.0, f32::NAN].saturating_into();
+        assert_eq!([1, 0, 255, 0], rgba);
+
+        let nested: [[u8; 2]; 2] = [[-1i16, 2], [300, 4]].saturating_into();
+        assert_eq!([[0, 2], [255, 4]], nested);
+
+        fn is_widening<T: SaturatingFrom<U>, U>() -> bool {
+            T::IS_WIDENING
+        }
+        assert!(is_widening::<[[u32; 2]; 3], [[u16; 2]; 3]>());
+        assert!(!is_widening::<[u8; 4], [i32; 4]>());
+    }
+
+    #[test]
+    fn impl_vec() {
+        let bytes: Vec<u8> = vec![-26i32, 76, 935, 255].saturating_into();
+        assert_eq!(vec![0, 76, 255, 255], bytes);
+        assert_eq!(Vec::<i8>::new(), Vec::saturating_from(Vec::<f64>::new()));
+
+        let src = vec![-1.5f32, 2.5, 1e10, f32::NAN];
+        assert_eq!(vec![-1, 2, i32::MAX, 0], Vec::<i32>::saturating_from(src));
+    }
+
+    #[test]
+    fn impl_range() {
+        assert_eq!(0u8..255, Range::saturating_from(-5i64..300));
+        assert_eq!(0usize..10, (-20i32..10).saturating_into());
+        assert_eq!(
+            10u8..10,
+            Range::saturating_from(Range {
+                start: 10i32,
+                end: -3
+            })
+        );
+        assert_eq!(
+            255u8..255,
+            Range::saturating_from(Range {
+                start: 400i32,
+                end: 300
+            })
+        );
+        assert!(Range::<u8>::saturating_from(300i32..400).is_empty());
+    }
+
+    #[test]
+    fn impl_range_inclusive() {
+        assert_eq!(0u8..=255, RangeInclusive::saturating_from(-5i64..=300));
+        assert_eq!(-128i8..=-3, (-1e9f64..=-3.5).saturating_into());
+        assert!(RangeInclusive::<u8>::saturating_from(RangeInclusive::new(10i32, -3)).is_empty());
+    }
+
+    #[test]
+    fn impl_range_inclusive_empty() {
+        // Both bounds clamp to the same value
+        let range = RangeInclusive::<u8>::saturating_from(RangeInclusive::new(400i32, 300));
+        assert!(range.is_empty());
+        let range = RangeInclusive::<i8>::saturating_from(RangeInclusive::new(-200f64, -300.0));
+        assert!(range.is_empty());
+
+        // A reversed range which stays reversed keeps its bounds
+        assert_eq!(
+            RangeInclusive::new(20u8, 10),
+            RangeInclusive::saturating_from(RangeInclusive::new(20i32, 10))
+        );
+
+        // An exhausted range is empty even though its bounds are equal
+        let mut range = 5i32..=5;
+        assert_eq!(Some(5), range.next());
+        assert!(range.is_empty());
+        let range = RangeInclusive::<u8>::saturating_from(range);
+        assert!(range.is_empty());
+        assert_eq!(None, range.clone().next());
+
+        let mut range = 0i64..=1000;
+        range.by_ref().for_each(drop);
+        assert!(RangeInclusive::<u8>::saturating_from(range).is_empty());
+    }
+}
@@ -0,0 +1,400 @@
+//! `const fn` saturating conversions between the fixed width integer types, and from floats to
+//! the fixed width integer types with the `const-float` feature.
+//!
+//! Trait methods can't be called in const contexts, so these functions provide the same
+//! conversions as [`SaturatingFrom`](crate::SaturatingFrom) for use in constants and statics. They
+//! are named `sat_<dst>_from_<src>`, and constant inputs are always evaluated at compile time.
+//!
+//! ```
+//! use saturate::consts::sat_u8_from_i32;
+//!
+//! const LEVEL: u8 = sat_u8_from_i32(300);
+//! assert_eq!(255, LEVEL);
+//! ```
+
+macro_rules! impl_const_widen {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts [`", stringify!($src), "`] to [`", stringify!($dst), "`]. This conversion is lossless")]
+            #[inline]
+            pub const fn $name(value: $src) -> $dst {
+                value as $dst
+            }
+        )+
+    };
+}
+
+macro_rules! impl_const_clamp {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts [`", stringify!($src), "`] to [`", stringify!($dst), "`], saturating out of range values")]
+            #[inline]
+            pub const fn $name(value: $src) -> $dst {
+                if value > <$dst>::MAX as $src {
+                    <$dst>::MAX
+                } else if value < <$dst>::MIN as $src {
+                    <$dst>::MIN
+                } else {
+                    value as $dst
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_const_clamp_unsigned {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts [`", stringify!($src), "`] to [`", stringify!($dst), "`], saturating out of range values")]
+            #[inline]
+            pub const fn $name(value: $src) -> $dst {
+                if value > <$dst>::MAX as $src {
+                    <$dst>::MAX
+                } else {
+                    value as $dst
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_const_clamp_signed {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts [`", stringify!($src), "`] to [`", stringify!($dst), "`], saturating negative values to zero")]
+            #[inline]
+            pub const fn $name(value: $src) -> $dst {
+                if value < 0 {
+                    0
+                } else {
+                    value as $dst
+                }
+            }
+        )+
+    };
+}
+
+// Float to integer `as` casts saturate and convert NaN to zero, but can only be used in a const
+// fn since Rust 1.82
+#[cfg(feature = "const-float")]
+macro_rules! impl_const_float {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts [`", stringify!($src), "`] to [`", stringify!($dst), "`], saturating out of range values and converting NaN to zero")]
+            #[inline]
+            pub const fn $name(value: $src) -> $dst {
+                value as $dst
+            }
+        )+
+    };
+}
+
+impl_const_widen!(
+    sat_i16_from_i8(i8) -> i16,
+    sat_i16_from_u8(u8) -> i16,
+    sat_u16_from_u8(u8) -> u16,
+    sat_i32_from_i8(i8) -> i32,
+    sat_i32_from_u8(u8) -> i32,
+    sat_i32_from_i16(i16) -> i32,
+    sat_i32_from_u16(u16) -> i32,
+    sat_u32_from_u8(u8) -> u32,
+    sat_u32_from_u16(u16) -> u32,
+    sat_i64_from_i8(i8) -> i64,
+    sat_i64_from_u8(u8) -> i64,
+    sat_i64_from_i16(i16) -> i64,
+    sat_i64_from_u16(u16) -> i64,
+    sat_i64_from_i32(i32) -> i64,
+    sat_i64_from_u32(u32) -> i64,
+    sat_u64_from_u8(u8) -> u64,
+    sat_u64_from_u16(u16) -> u64,
+    sat_u64_from_u32(u32) -> u64,
+    sat_i128_from_i8(i8) -> i128,
+    sat_i128_from_u8(u8) -> i128,
+    sat_i128_from_i16(i16) -> i128,
+    sat_i128_from_u16(u16) -> i128,
+    sat_i128_from_i32(i32) -> i128,
+    sat_i128_from_u32(u32) -> i128,
+    sat_i128_from_i64(i64) -> i128,
+    sat_i128_from_u64(u64) -> i128,
+    sat_u128_from_u8(u8) -> u128,
+    sat_u128_from_u16(u16) -> u128,
+    sat_u128_from_u32(u32) -> u128,
+    sat_u128_from_u64(u64) -> u128,
+);
+
+impl_const_clamp!(
+    sat_i8_from_i16(i16) -> i8,
+    sat_i8_from_i32(i32) -> i8,
+    sat_i8_from_i64(i64) -> i8,
+    sat_i8_from_i128(i128) -> i8,
+    sat_u8_from_i16(i16) -> u8,
+    sat_u8_from_u16(u16) -> u8,
+    sat_u8_from_i32(i32) -> u8,
+    sat_u8_from_u32(u32) -> u8,
+    sat_u8_from_i64(i64) -> u8,
+    sat_u8_from_u64(u64) -> u8,
+    sat_u8_from_i128(i128) -> u8,
+    sat_u8_from_u128(u128) -> u8,
+    sat_i16_from_i32(i32) -> i16,
+    sat_i16_from_i64(i64) -> i16,
+    sat_i16_from_i128(i128) -> i16,
+    sat_u16_from_i32(i32) -> u16,
+    sat_u16_from_u32(u32) -> u16,
+    sat_u16_from_i64(i64) -> u16,
+    sat_u16_from_u64(u64) -> u16,
+    sat_u16_from_i128(i128) -> u16,
+    sat_u16_from_u128(u128) -> u16,
+    sat_i32_from_i64(i64) -> i32,
+    sat_i32_from_i128(i128) -> i32,
+    sat_u32_from_i64(i64) -> u32,
+    sat_u32_from_u64(u64) -> u32,
+    sat_u32_from_i128(i128) -> u32,
+    sat_u32_from_u128(u128) -> u32,
+    sat_i64_from_i128(i128) -> i64,
+    sat_u64_from_i128(i128) -> u64,
+    sat_u64_from_u128(u128) -> u64,
+);
+
+impl_const_clamp_unsigned!(
+    sat_i8_from_u8(u8) -> i8,
+    sat_i8_from_u16(u16) -> i8,
+    sat_i8_from_u32(u32) -> i8,
+    sat_i8_from_u64(u64) -> i8,
+    sat_i8_from_u128(u128) -> i8,
+    sat_i16_from_u16(u16) -> i16,
+    sat_i16_from_u32(u32) -> i16,
+    sat_i16_from_u64(u64) -> i16,
+    sat_i16_from_u128(u128) -> i16,
+    sat_i32_from_u32(u32) -> i32,
+    sat_i32_from_u64(u64) -> i32,
+    sat_i32_from_u128(u128) -> i32,
+    sat_i64_from_u64(u64) -> i64,
+    sat_i64_from_u128(u128) -> i64,
+    sat_i128_from_u128(u128) -> i128,
+);
+
+impl_const_clamp_signed!(
+    sat_u8_from_i8(i8) -> u8,
+    sat_u16_from_i8(i8) -> u16,
+    sat_u16_from_i16(i16) -> u16,
+    sat_u32_from_i8(i8) -> u32,
+    sat_u32_from_i16(i16) -> u32,
+    sat_u32_from_i32(i32) -> u32,
+    sat_u64_from_i8(i8) -> u64,
+    sat_u64_from_i16(i16) -> u64,
+    sat_u64_from_i32(i32) -> u64,
+    sat_u64_from_i64(i64) -> u64,
+    sat_u128_from_i8(i8) -> u128,
+    sat_u128_from_i16(i16) -> u128,
+    sat_u128_from_i32(i32) -> u128,
+    sat_u128_from_i64(i64) -> u128,
+    sat_u128_from_i128(i128) -> u128,
+);
+
+#[cfg(feature = "const-float")]
+impl_const_float!(
+    sat_i8_from_f32(f32) -> i8,
+    sat_i8_from_f64(f64) -> i8,
+    sat_u8_from_f32(f32) -> u8,
+    sat_u8_from_f64(f64) -> u8,
+    sat_i16_from_f32(f32) -> i16,
+    sat_i16_from_f64(f64) -> i16,
+    sat_u16_from_f32(f32) -> u16,
+    sat_u16_from_f64(f64) -> u16,
+    sat_i32_from_f32(f32) -> i32,
+    sat_i32_from_f64(f64) -> i32,
+    sat_u32_from_f32(f32) -> u32,
+    sat_u32_from_f64(f64) -> u32,
+    sat_i64_from_f32(f32) -> i64,
+    sat_i64_from_f64(f64) -> i64,
+    sat_u64_from_f32(f32) -> u64,
+    sat_u64_from_f64(f64) -> u64,
+    sat_i128_from_f32(f32) -> i128,
+    sat_i128_from_f64(f64) -> i128,
+    sat_u128_from_f32(f32) -> u128,
+    sat_u128_from_f64(f64) -> u128,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingFrom;
+
+    #[test]
+    fn const_context() {
+        const CLAMPED: u8 = sat_u8_from_i32(300);
+        const NEGATIVE: u32 = sat_u32_from_i8(-5);
+        const WIDENED: i64 = sat_i64_from_u32(u32::MAX);
+        const NARROWED: i16 = sat_i16_from_i128(i128::MIN);
+        assert_eq!(255, CLAMPED);
+        assert_eq!(0, NEGATIVE);
+        assert_eq!(i64::from(u32::MAX), WIDENED);
+        assert_eq!(i16::MIN, NARROWED);
+    }
+
+    #[test]
+    fn matches_saturating_from() {
+        macro_rules! check {
+            ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+                $(
+                    let samples = [
+                        <$src>::MIN, <$src>::MIN + 1, <$src>::MIN / 2, 0, 1, 100, <$src>::MAX / 2,
+                        <$src>::MAX - 1, <$src>::MAX,
+                        <$src>::saturating_from(<$dst>::MIN), <$src>::saturating_from(<$dst>::MAX),
+                    ];
+                    for &value in samples.iter() {
+                        assert_eq!(<$dst>::saturating_from(value), $name(value), "{}", value);
+                    }
+                )+
+            };
+        }
+
+        check!(
+            sat_i16_from_i8(i8) -> i16,
+            sat_i16_from_u8(u8) -> i16,
+            sat_u16_from_u8(u8) -> u16,
+            sat_i32_from_i8(i8) -> i32,
+            sat_i32_from_u8(u8) -> i32,
+            sat_i32_from_i16(i16) -> i32,
+            sat_i32_from_u16(u16) -> i32,
+            sat_u32_from_u8(u8) -> u32,
+            sat_u32_from_u16(u16) -> u32,
+            sat_i64_from_i8(i8) -> i64,
+            sat_i64_from_u8(u8) -> i64,
+            sat_i64_from_i16(i16) -> i64,
+            sat_i64_from_u16(u16) -> i64,
+            sat_i64_from_i32(i32) -> i64,
+            sat_i64_from_u32(u32) -> i64,
+            sat_u64_from_u8(u8) -> u64,
+            sat_u64_from_u16(u16) -> u64,
+            sat_u64_from_u32(u32) -> u64,
+            sat_i128_from_i8(i8) -> i128,
+            sat_i128_from_u8(u8) -> i128,
+            sat_i128_from_i16(i16) -> i128,
+            sat_i128_from_u16(u16) -> i128,
+            sat_i128_from_i32(i32) -> i128,
+            sat_i128_from_u32(u32) -> i128,
+            sat_i128_from_i64(i64) -> i128,
+            sat_i128_from_u64(u64) -> i128,
+            sat_u128_from_u8(u8) -> u128,
+            sat_u128_from_u16(u16) -> u128,
+            sat_u128_from_u32(u32) -> u128,
+            sat_u128_from_u64(u64) -> u128,
+            sat_i8_from_i16(i16) -> i8,
+            sat_i8_from_i32(i32) -> i8,
+            sat_i8_from_i64(i64) -> i8,
+            sat_i8_from_i128(i128) -> i8,
+            sat_u8_from_i16(i16) -> u8,
+            sat_u8_from_u16(u16) -> u8,
+            sat_u8_from_i32(i32) -> u8,
+            sat_u8_from_u32(u32) -> u8,
+            sat_u8_from_i64(i64) -> u8,
+            sat_u8_from_u64(u64) -> u8,
+            sat_u8_from_i128(i128) -> u8,
+            sat_u8_from_u128(u128) -> u8,
+            sat_i16_from_i32(i32) -> i16,
+            sat_i16_from_i64(i64) -> i16,
+            sat_i16_from_i128(i128) -> i16,
+            sat_u16_from_i32(i32) -> u16,
+            sat_u16_from_u32(u32) -> u16,
+            sat_u16_from_i64(i64) -> u16,
+            sat_u16_from_u64(u64) -> u16,
+            sat_u16_from_i128(i128) -> u16,
+            sat_u16_from_u128(u128) -> u16,
+            sat_i32_from_i64(i64) -> i32,
+            sat_i32_from_i128(i128) -> i32,
+            sat_u32_from_i64(i64) -> u32,
+            sat_u32_from_u64(u64) -> u32,
+            sat_u32_from_i128(i128) -> u32,
+            sat_u32_from_u128(u128) -> u32,
+            sat_i64_from_i128(i128) -> i64,
+            sat_u64_from_i128(i128) -> u64,
+            sat_u64_from_u128(u128) -> u64,
+            sat_i8_from_u8(u8) -> i8,
+            sat_i8_from_u16(u16) -> i8,
+            sat_i8_from_u32(u32) -> i8,
+            sat_i8_from_u64(u64) -> i8,
+            sat_i8_from_u128(u128) -> i8,
+            sat_i16_from_u16(u16) -> i16,
+            sat_i16_from_u32(u32) -> i16,
+            sat_i16_from_u64(u64) -> i16,
+            sat_i16_from_u128(u128) -> i16,
+            sat_i32_from_u32(u32) -> i32,
+            sat_i32_from_u64(u64) -> i32,
+            sat_i32_from_u128(u128) -> i32,
+            sat_i64_from_u64(u64) -> i64,
+            sat_i64_from_u128(u128) -> i64,
+            sat_i128_from_u128(u128) -> i128,
+            sat_u8_from_i8(i8) -> u8,
+            sat_u16_from_i8(i8) -> u16,
+            sat_u16_from_i16(i16) -> u16,
+            sat_u32_from_i8(i8) -> u32,
+            sat_u32_from_i16(i16) -> u32,
+            sat_u32_from_i32(i32) -> u32,
+            sat_u64_from_i8(i8) -> u64,
+            sat_u64_from_i16(i16) -> u64,
+            sat_u64_from_i32(i32) -> u64,
+            sat_u64_from_i64(i64) -> u64,
+            sat_u128_from_i8(i8) -> u128,
+            sat_u128_from_i16(i16) -> u128,
+            sat_u128_from_i32(i32) -> u128,
+            sat_u128_from_i64(i64) -> u128,
+            sat_u128_from_i128(i128) -> u128,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "const-float")]
+    fn const_float_context() {
+        const CLAMPED: i32 = sat_i32_from_f64(1e300);
+        const NEGATIVE: u8 = sat_u8_from_f32(-1.5);
+        const NAN: i64 = sat_i64_from_f64(f64::NAN);
+        const TRUNCATED: i16 = sat_i16_from_f32(-2.7);
+        assert_eq!(i32::MAX, CLAMPED);
+        assert_eq!(0, NEGATIVE);
+        assert_eq!(0, NAN);
+        assert_eq!(-2, TRUNCATED);
+    }
+
+    #[test]
+    #[cfg(feature = "const-float")]
+    fn const_float_matches_saturating_from() {
+        macro_rules! check {
+            ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+                $(
+                    let samples = [
+                        <$src>::NEG_INFINITY, <$src>::MIN, -1.5, -0.5, -0.0, 0.0, 0.5, 1.5,
+                        <$src>::MAX, <$src>::INFINITY, <$src>::NAN,
+                        <$src>::saturating_from(<$dst>::MIN), <$src>::saturating_from(<$dst>::MAX),
+                    ];
+                    for &value in samples.iter() {
+                        assert_eq!(<$dst>::saturating_from(value), $name(value), "{}", value);
+                    }
+                )+
+            };
+        }
+
+        check!(
+            sat_i8_from_f32(f32) -> i8,
+            sat_i8_from_f64(f64) -> i8,
+            sat_u8_from_f32(f32) -> u8,
+            sat_u8_from_f64(f64) -> u8,
+            sat_i16_from_f32(f32) -> i16,
+            sat_i16_from_f64(f64) -> i16,
+            sat_u16_from_f32(f32) -> u16,
+            sat_u16_from_f64(f64) -> u16,
+            sat_i32_from_f32(f32) -> i32,
+            sat_i32_from_f64(f64) -> i32,
+            sat_u32_from_f32(f32) -> u32,
+            sat_u32_from_f64(f64) -> u32,
+            sat_i64_from_f32(f32) -> i64,
+            sat_i64_from_f64(f64) -> i64,
+            sat_u64_from_f32(f32) -> u64,
+            sat_u64_from_f64(f64) -> u64,
+            sat_i128_from_f32(f32) -> i128,
+            sat_i128_from_f64(f64) -> i128,
+            sat_u128_from_f32(f32) -> u128,
+            sat_u128_from_f64(f64) -> u128,
+        );
+    }
+}
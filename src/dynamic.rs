@@ -0,0 +1,329 @@
+//! Saturating conversions between numeric types only known at runtime.
+
+use core::marker::PhantomData;
+
+use crate::SaturatingFrom;
+
+/// A primitive numeric type, see [`NumericValue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericType {
+    /// [`bool`]
+    Bool,
+    /// [`i8`]
+    I8,
+    /// [`u8`]
+    U8,
+    /// [`i16`]
+    I16,
+    /// [`u16`]
+    U16,
+    /// [`i32`]
+    I32,
+    /// [`u32`]
+    U32,
+    /// [`i64`]
+    I64,
+    /// [`u64`]
+    U64,
+    /// [`i128`]
+    I128,
+    /// [`u128`]
+    U128,
+    /// [`isize`]
+    Isize,
+    /// [`usize`]
+    Usize,
+    /// [`f32`]
+    F32,
+    /// [`f64`]
+    F64,
+}
+
+/// A value of any primitive numeric type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericValue {
+    /// A [`bool`] value
+    Bool(bool),
+    /// An [`i8`] value
+    I8(i8),
+    /// A [`u8`] value
+    U8(u8),
+    /// An [`i16`] value
+    I16(i16),
+    /// A [`u16`] value
+    U16(u16),
+    /// An [`i32`] value
+    I32(i32),
+    /// A [`u32`] value
+    U32(u32),
+    /// An [`i64`] value
+    I64(i64),
+    /// A [`u64`] value
+    U64(u64),
+    /// An [`i128`] value
+    I128(i128),
+    /// A [`u128`] value
+    U128(u128),
+    /// An [`isize`] value
+    Isize(isize),
+    /// A [`usize`] value
+    Usize(usize),
+    /// An [`f32`] value
+    F32(f32),
+    /// An [`f64`] value
+    F64(f64),
+}
+
+impl NumericValue {
+    /// Returns the type of the value
+    #[inline]
+    pub fn numeric_type(&self) -> NumericType {
+        match self {
+            NumericValue::Bool(_) => NumericType::Bool,
+            NumericValue::I8(_) => NumericType::I8,
+            NumericValue::U8(_) => NumericType::U8,
+            NumericValue::I16(_) => NumericType::I16,
+            NumericValue::U16(_) => NumericType::U16,
+            NumericValue::I32(_) => NumericType::I32,
+            NumericValue::U32(_) => NumericType::U32,
+            NumericValue::I64(_) => NumericType::I64,
+            NumericValue::U64(_) => NumericType::U64,
+            NumericValue::I128(_) => NumericType::I128,
+            NumericValue::U128(_) => NumericType::U128,
+            NumericValue::Isize(_) => NumericType::Isize,
+            NumericValue::Usize(_) => NumericType::Usize,
+            NumericValue::F32(_) => NumericType::F32,
+            NumericValue::F64(_) => NumericType::F64,
+        }
+    }
+}
+
+/// Object safe trait for a saturating conversion between two numeric types, see [`converter`].
+pub trait DynSaturatingConvert {
+    /// Converts `value` to the target type
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not of the source type.
+    fn convert(&self, value: NumericValue) -> NumericValue;
+}
+
+// Maps a primitive type to and from the corresponding NumericValue variant
+trait Primitive: Sized {
+    const TYPE: NumericType;
+
+    fn from_value(value: NumericValue) -> Option<Self>;
+
+    fn into_value(self) -> NumericValue;
+}
+
+macro_rules! impl_primitive {
+    ($($variant:ident: $typ:ty),+) => {
+        $(
+            impl Primitive for $typ {
+                const TYPE: NumericType = NumericType::$variant;
+
+                #[inline]
+                fn from_value(value: NumericValue) -> Option<$typ> {
+                    match value {
+                        NumericValue::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                #[inline]
+                fn into_value(self) -> NumericValue {
+                    NumericValue::$variant(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive!(
+    Bool: bool, I8: i8, U8: u8, I16: i16, U16: u16, I32: i32, U32: u32, I64: i64, U64: u64,
+    I128: i128, U128: u128, Isize: isize, Usize: usize, F32: f32, F64: f64
+);
+
+// Zero sized converter from Src to Dst
+struct Converter<Src, Dst>(PhantomData<fn(Src) -> Dst>);
+
+impl<Src, Dst> DynSaturatingConvert for Converter<Src, Dst>
+where
+    Src: Primitive,
+    Dst: Primitive + SaturatingFrom<Src>,
+{
+    #[inline]
+    fn convert(&self, value: NumericValue) -> NumericValue {
+        match Src::from_value(value) {
+            Some(value) => Dst::saturating_from(value).into_value(),
+            None => panic!(
+                "expected a {:?} value, found {:?}",
+                Src::TYPE,
+                value.numeric_type()
+            ),
+        }
+    }
+}
+
+/// Returns the converter from `src` to `dst`.
+///
+/// ```
+/// use saturate::dynamic::{converter, NumericType, NumericValue};
+///
+/// let convert = converter(NumericType::I64, NumericType::U8);
+/// assert_eq!(NumericValue::U8(255), convert.convert(NumericValue::I64(300)));
+/// assert_eq!(NumericValue::U8(0), convert.convert(NumericValue::I64(-1)));
+/// ```
+pub fn converter(src: NumericType, dst: NumericType) -> &'static dyn DynSaturatingConvert {
+    macro_rules! dispatch {
+        ($($variant:ident: $typ:ty),+) => {
+            dispatch!(@src [$($variant: $typ),+]; $($variant: $typ),+)
+        };
+        (@src $dsts:tt; $($variant:ident: $src:ty),+) => {
+            match src {
+                $(NumericType::$variant => dispatch!(@dst $src; $dsts),)+
+            }
+        };
+        (@dst $src:ty; [$($variant:ident: $dst:ty),+]) => {
+            match dst {
+                $(NumericType::$variant => &Converter::<$src, $dst>(PhantomData),)+
+            }
+        };
+    }
+
+    dispatch!(
+        Bool: bool, I8: i8, U8: u8, I16: i16, U16: u16, I32: i32, U32: u32, I64: i64, U64: u64,
+        I128: i128, U128: u128, Isize: isize, Usize: usize, F32: f32, F64: f64
+    )
+}
+
+macro_rules! impl_to_type {
+    ($($variant:ident: $typ:ty),+) => {
+        /// Converts `value` to the type selected by `ty`.
+        ///
+        /// ```
+        /// use saturate::dynamic::{saturating_to_type, NumericType, NumericValue};
+        ///
+        /// assert_eq!(NumericValue::U8(255), saturating_to_type(300i64, NumericType::U8));
+        /// assert_eq!(NumericValue::F32(300.0), saturating_to_type(300i64, NumericType::F32));
+        /// ```
+        #[inline]
+        pub fn saturating_to_type<T>(value: T, ty: NumericType) -> NumericValue
+        where
+            $($typ: SaturatingFrom<T>,)+
+        {
+            match ty {
+                $(NumericType::$variant => NumericValue::$variant(<$typ>::saturating_from(value)),)+
+            }
+        }
+    };
+}
+
+impl_to_type!(
+    Bool: bool, I8: i8, U8: u8, I16: i16, U16: u16, I32: i32, U32: u32, I64: i64, U64: u64,
+    I128: i128, U128: u128, Isize: isize, Usize: usize, F32: f32, F64: f64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_convert() {
+        let convert = converter(NumericType::I64, NumericType::U8);
+        assert_eq!(
+            NumericValue::U8(255),
+            convert.convert(NumericValue::I64(300))
+        );
+        assert_eq!(NumericValue::U8(0), convert.convert(NumericValue::I64(-1)));
+        assert_eq!(NumericValue::U8(42), convert.convert(NumericValue::I64(42)));
+
+        let convert = converter(NumericType::F64, NumericType::I16);
+        assert_eq!(
+            NumericValue::I16(i16::MIN),
+            convert.convert(NumericValue::F64(-1e10))
+        );
+        assert_eq!(
+            NumericValue::I16(0),
+            convert.convert(NumericValue::F64(f64::NAN))
+        );
+
+        let convert = converter(NumericType::U128, NumericType::F32);
+        assert_eq!(
+            NumericValue::F32(f32::INFINITY),
+            convert.convert(NumericValue::U128(u128::MAX))
+        );
+
+        let convert = converter(NumericType::Bool, NumericType::Bool);
+        assert_eq!(
+            NumericValue::Bool(true),
+            convert.convert(NumericValue::Bool(true))
+        );
+    }
+
+    #[test]
+    fn all_pairs() {
+        let types = [
+            NumericType::Bool,
+            NumericType::I8,
+            NumericType::U8,
+            NumericType::I16,
+            NumericType::U16,
+            NumericType::I32,
+            NumericType::U32,
+            NumericType::I64,
+            NumericType::U64,
+            NumericType::I128,
+            NumericType::U128,
+            NumericType::Isize,
+            NumericType::Usize,
+            NumericType::F32,
+            NumericType::F64,
+        ];
+        for &src in &types {
+            // Every type can represent one, and converting it back is exact
+            let one = converter(NumericType::U8, src).convert(NumericValue::U8(1));
+            assert_eq!(src, one.numeric_type());
+            for &dst in &types {
+                let result = converter(src, dst).convert(one);
+                assert_eq!(dst, result.numeric_type());
+                assert_eq!(one, converter(dst, src).convert(result));
+            }
+        }
+    }
+
+    #[test]
+    fn to_type() {
+        assert_eq!(
+            NumericValue::U8(255),
+            saturating_to_type(300i64, NumericType::U8)
+        );
+        assert_eq!(
+            NumericValue::I8(-128),
+            saturating_to_type(-1e10f64, NumericType::I8)
+        );
+        assert_eq!(
+            NumericValue::Bool(true),
+            saturating_to_type(2u16, NumericType::Bool)
+        );
+        assert_eq!(
+            NumericValue::U64(0),
+            saturating_to_type(f32::NAN, NumericType::U64)
+        );
+        assert_eq!(
+            NumericValue::F64(-1.5),
+            saturating_to_type(-1.5f32, NumericType::F64)
+        );
+        assert_eq!(
+            NumericValue::I128(i128::from(i64::MIN)),
+            saturating_to_type(i64::MIN, NumericType::I128)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn dyn_convert_wrong_type() {
+        converter(NumericType::I64, NumericType::U8).convert(NumericValue::I32(1));
+    }
+}
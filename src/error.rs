@@ -0,0 +1,130 @@
+use core::fmt;
+use core::num::FpCategory;
+
+/// Error returned when converting a non-finite floating point value, see
+/// [`SaturatingFromFinite`](crate::SaturatingFromFinite)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFinite {
+    category: FpCategory,
+}
+
+impl NonFinite {
+    pub(crate) fn new(category: FpCategory) -> Self {
+        Self { category }
+    }
+
+    /// Returns the category of the value, either [`FpCategory::Infinite`] or [`FpCategory::Nan`]
+    pub fn category(&self) -> FpCategory {
+        self.category
+    }
+}
+
+impl fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.category {
+            FpCategory::Nan => f.write_str("cannot convert NaN value"),
+            _ => f.write_str("cannot convert infinite value"),
+        }
+    }
+}
+
+impl std::error::Error for NonFinite {}
+
+/// Error returned when a value was out of range and had to be clamped, see
+/// [`SaturatingIntoChecked`](crate::SaturatingIntoChecked)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clamped<T> {
+    /// The saturated value, as would be returned by
+    /// [`saturating_into`](crate::SaturatingInto::saturating_into)
+    pub value: T,
+}
+
+impl<T: fmt::Display> fmt::Display for Clamped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value out of range, clamped to {}", self.value)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for Clamped<T> {}
+
+/// Error returned when decoding a buffer of integers, see
+/// [`saturating_from_be_slice`](crate::saturating_from_be_slice)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The width of each integer was zero or greater than 16 bytes
+    InvalidWidth,
+    /// The length of the buffer was not a multiple of the width
+    InvalidLength,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidWidth => {
+                f.write_str("integer width must be between 1 and 16 bytes")
+            }
+            DecodeError::InvalidLength => {
+                f.write_str("buffer length is not a multiple of the integer width")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Error passed to the fallback of [`SaturatingFromOrElse`](crate::SaturatingFromOrElse),
+/// containing the original value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError<T> {
+    /// The value was greater than the target's maximum
+    AboveMax(T),
+    /// The value was less than the target's minimum
+    BelowMin(T),
+    /// The value was NaN
+    Nan(T),
+}
+
+impl<T> RangeError<T> {
+    /// Returns the original value
+    #[inline]
+    pub fn into_value(self) -> T {
+        match self {
+            RangeError::AboveMax(value) | RangeError::BelowMin(value) | RangeError::Nan(value) => {
+                value
+            }
+        }
+    }
+}
+
+impl<T> fmt::Display for RangeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::AboveMax(_) => f.write_str("value is greater than the maximum"),
+            RangeError::BelowMin(_) => f.write_str("value is less than the minimum"),
+            RangeError::Nan(_) => f.write_str("value is NaN"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for RangeError<T> {}
+
+/// Error returned when parsing an integer from ASCII, see
+/// [`SaturatingFromAscii`](crate::text::SaturatingFromAscii)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input was empty
+    Empty,
+    /// The input contained a byte other than a digit or a valid leading sign
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("cannot parse integer from empty input"),
+            ParseError::InvalidDigit => f.write_str("invalid digit found in input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,174 @@
+//! Named saturating conversions to and from pointer-sized integers.
+//!
+//! These are equivalent to the corresponding [`SaturatingFrom`] implementations, which behave the
+//! same way on 16, 32, and 64-bit targets: values are clamped to the range of the destination
+//! type on the target being compiled for. The explicit names make conversions at FFI boundaries
+//! easy to find and review.
+//!
+//! The C type aliases in [`std::os::raw`] (such as `c_int` and `c_long`) are aliases of the
+//! primitive types on every platform, so [`SaturatingFrom`] can be used with them directly.
+//!
+//! ```
+//! use saturate::ffi::clamp_usize_to_u32;
+//!
+//! let len: usize = 1234;
+//! assert_eq!(1234, clamp_usize_to_u32(len));
+//! ```
+
+use crate::SaturatingFrom;
+
+macro_rules! impl_clamp_fn {
+    ($($name:ident($src:ty) -> $dst:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Converts a `", stringify!($src), "` to a `", stringify!($dst), "`, saturating at the bounds of `", stringify!($dst), "`")]
+            #[inline]
+            pub fn $name(value: $src) -> $dst {
+                <$dst>::saturating_from(value)
+            }
+        )+
+    };
+}
+
+impl_clamp_fn!(
+    clamp_usize_to_u8(usize) -> u8,
+    clamp_usize_to_u16(usize) -> u16,
+    clamp_usize_to_u32(usize) -> u32,
+    clamp_usize_to_u64(usize) -> u64,
+    clamp_usize_to_i32(usize) -> i32,
+    clamp_usize_to_i64(usize) -> i64,
+    clamp_usize_to_isize(usize) -> isize,
+);
+
+impl_clamp_fn!(
+    clamp_isize_to_i8(isize) -> i8,
+    clamp_isize_to_i16(isize) -> i16,
+    clamp_isize_to_i32(isize) -> i32,
+    clamp_isize_to_i64(isize) -> i64,
+    clamp_isize_to_u32(isize) -> u32,
+    clamp_isize_to_u64(isize) -> u64,
+    clamp_isize_to_usize(isize) -> usize,
+);
+
+impl_clamp_fn!(
+    clamp_u32_to_usize(u32) -> usize,
+    clamp_u64_to_usize(u64) -> usize,
+    clamp_u128_to_usize(u128) -> usize,
+    clamp_i64_to_usize(i64) -> usize,
+    clamp_i32_to_isize(i32) -> isize,
+    clamp_i64_to_isize(i64) -> isize,
+    clamp_i128_to_isize(i128) -> isize,
+    clamp_u64_to_isize(u64) -> isize,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_size() {
+        assert_eq!(255, clamp_usize_to_u8(usize::MAX));
+        assert_eq!(1234, clamp_usize_to_u16(1234));
+        assert_eq!(
+            u64::MAX >> (64 - usize::BITS),
+            clamp_usize_to_u64(usize::MAX)
+        );
+        assert_eq!(isize::MAX, clamp_usize_to_isize(usize::MAX));
+        assert_eq!(-128, clamp_isize_to_i8(isize::MIN));
+        assert_eq!(0, clamp_isize_to_u32(-1));
+        assert_eq!(0, clamp_isize_to_usize(isize::MIN));
+        assert_eq!(i64::from(i16::MIN), clamp_isize_to_i64(-0x8000));
+
+        #[cfg(target_pointer_width = "16")]
+        {
+            assert_eq!(0xffff, clamp_usize_to_u32(usize::MAX));
+            assert_eq!(0xffff, clamp_usize_to_i32(usize::MAX));
+            assert_eq!(-0x8000, clamp_isize_to_i32(isize::MIN));
+            assert_eq!(0x7fff, clamp_isize_to_u64(isize::MAX));
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_eq!(u32::MAX, clamp_usize_to_u32(usize::MAX));
+            assert_eq!(i32::MAX, clamp_usize_to_i32(usize::MAX));
+            assert_eq!(i32::MIN, clamp_isize_to_i32(isize::MIN));
+            assert_eq!(0x7fff_ffff, clamp_isize_to_u64(isize::MAX));
+        }
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(u32::MAX, clamp_usize_to_u32(usize::MAX));
+            assert_eq!(i32::MAX, clamp_usize_to_i32(usize::MAX));
+            assert_eq!(i32::MIN, clamp_isize_to_i32(isize::MIN));
+            assert_eq!(i64::MAX as u64, clamp_isize_to_u64(isize::MAX));
+            assert_eq!(i64::MAX, clamp_usize_to_i64(usize::MAX));
+        }
+    }
+
+    #[test]
+    fn to_size() {
+        assert_eq!(usize::MAX, clamp_u128_to_usize(u128::MAX));
+        assert_eq!(usize::MAX, clamp_u64_to_usize(u64::MAX));
+        assert_eq!(0, clamp_i64_to_usize(-1));
+        assert_eq!(isize::MIN, clamp_i128_to_isize(i128::MIN));
+        assert_eq!(isize::MAX, clamp_u64_to_isize(u64::MAX));
+        assert_eq!(1234, clamp_u32_to_usize(1234));
+        assert_eq!(-1234, clamp_i32_to_isize(-1234));
+
+        #[cfg(target_pointer_width = "16")]
+        {
+            assert_eq!(0xffff, clamp_u32_to_usize(u32::MAX));
+            assert_eq!(-0x8000, clamp_i64_to_isize(i64::MIN));
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_eq!(0xffff_ffff, clamp_u32_to_usize(u32::MAX));
+            assert_eq!(-0x8000_0000, clamp_i64_to_isize(i64::MIN));
+        }
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(0xffff_ffff, clamp_u32_to_usize(u32::MAX));
+            assert_eq!(i64::MIN as isize, clamp_i64_to_isize(i64::MIN));
+        }
+    }
+
+    #[test]
+    fn c_types() {
+        use std::os::raw::{
+            c_char, c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short, c_uchar,
+            c_uint, c_ulong, c_ulonglong, c_ushort,
+        };
+
+        // c_long is 64-bit on LP64 targets, but 32-bit on LLP64 (e.g. 64-bit Windows) and 32-bit
+        // targets
+        assert_eq!(u8::MAX, u8::saturating_from(c_long::MAX));
+        assert_eq!(0u8, u8::saturating_from(c_long::MIN));
+        assert_eq!(i32::MAX, i32::saturating_from(c_long::MAX));
+        assert_eq!(c_long::MAX, c_long::saturating_from(i64::MAX));
+        assert_eq!(c_long::MIN, c_long::saturating_from(i128::MIN));
+        assert_eq!(c_ulong::MAX, c_ulong::saturating_from(u128::MAX));
+        assert_eq!(0, c_ulong::saturating_from(-1i8));
+        if core::mem::size_of::<c_long>() == 8 {
+            assert_eq!(i64::MAX, i64::saturating_from(c_long::MAX));
+            assert_eq!(u32::MAX, u32::saturating_from(c_ulong::MAX));
+        } else {
+            assert_eq!(i64::from(i32::MAX), i64::saturating_from(c_long::MAX));
+            assert_eq!(
+                c_long::MAX,
+                c_long::saturating_from(i64::from(i32::MAX) + 1)
+            );
+        }
+
+        assert_eq!(c_int::MAX, c_int::saturating_from(u64::MAX));
+        assert_eq!(0, c_uint::saturating_from(c_int::MIN));
+        assert_eq!(c_short::MIN, c_short::saturating_from(c_int::MIN));
+        assert_eq!(c_ushort::MAX, c_ushort::saturating_from(c_uint::MAX));
+        assert_eq!(
+            c_longlong::MAX,
+            c_longlong::saturating_from(c_ulonglong::MAX)
+        );
+        assert_eq!(127, c_schar::saturating_from(300i32));
+        assert_eq!(255, c_uchar::saturating_from(300i32));
+        assert_eq!(b'A' as c_char, c_char::saturating_from(b'A'));
+        assert_eq!(127, u8::saturating_from(c_char::saturating_from(127u8)));
+        assert_eq!(c_int::MAX, c_int::saturating_from(c_double::INFINITY));
+        assert!(c_float::saturating_from(c_double::MAX).is_infinite());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{NonFinite, SaturatingFrom};
+
+/// Trait to perform a saturating conversion from a floating point type, rejecting non-finite
+/// values instead of saturating them.
+///
+/// ```
+/// use core::num::FpCategory;
+/// use saturate::SaturatingFromFinite;
+///
+/// assert_eq!(Ok(i32::MAX), i32::saturating_from_finite(1e300));
+/// let err = i32::saturating_from_finite(f64::INFINITY).unwrap_err();
+/// assert_eq!(FpCategory::Infinite, err.category());
+/// ```
+pub trait SaturatingFromFinite<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, returning an error if `value` is infinite or NaN
+    fn saturating_from_finite(value: T) -> Result<Self, NonFinite>;
+}
+
+macro_rules! impl_finite {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromFinite<$src> for $dst {
+                #[inline]
+                fn saturating_from_finite(value: $src) -> Result<$dst, NonFinite> {
+                    if value.is_finite() {
+                        Ok(<$dst>::saturating_from(value))
+                    } else {
+                        Err(NonFinite::new(value.classify()))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_finite!([f32, f64] => u8);
+impl_finite!([f32, f64] => u16);
+impl_finite!([f32, f64] => u32);
+impl_finite!([f32, f64] => u64);
+impl_finite!([f32, f64] => u128);
+impl_finite!([f32, f64] => usize);
+
+impl_finite!([f32, f64] => i8);
+impl_finite!([f32, f64] => i16);
+impl_finite!([f32, f64] => i32);
+impl_finite!([f32, f64] => i64);
+impl_finite!([f32, f64] => i128);
+impl_finite!([f32, f64] => isize);
+
+#[cfg(test)]
+mod tests {
+    use core::num::FpCategory;
+
+    use super::*;
+
+    #[test]
+    fn impl_finite() {
+        assert_eq!(Ok(i32::MAX), i32::saturating_from_finite(1e300f64));
+        assert_eq!(Ok(0u8), u8::saturating_from_finite(-3.5f32));
+        assert_eq!(Ok(-2i64), i64::saturating_from_finite(-2.9f64));
+        assert_eq!(Ok(0usize), usize::saturating_from_finite(f64::MIN_POSITIVE));
+
+        let err = i32::saturating_from_finite(f64::INFINITY).unwrap_err();
+        assert_eq!(FpCategory::Infinite, err.category());
+        let err = u16::saturating_from_finite(f32::NEG_INFINITY).unwrap_err();
+        assert_eq!(FpCategory::Infinite, err.category());
+        let err = i128::saturating_from_finite(f32::NAN).unwrap_err();
+        assert_eq!(FpCategory::Nan, err.category());
+    }
+}
@@ -0,0 +1,235 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::SaturatingFrom;
+
+/// Extension trait providing saturating conversions for the items of an [`Iterator`].
+///
+/// This trait is implemented for all iterators.
+pub trait SaturatingIterator: Iterator {
+    /// Returns an iterator which converts each item to `Dst`.
+    ///
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use saturate::SaturatingIterator;
+    ///
+    /// let bytes: Vec<u8> = [-26i32, 76, 935].iter().copied().saturating_map_into().collect();
+    /// assert_eq!([0, 76, 255], *bytes);
+    ///
+    /// let mut counts = [0u32, 300].iter().copied().saturating_map_into::<NonZeroU8>();
+    /// assert_eq!(Some(1), counts.next().map(NonZeroU8::get));
+    /// ```
+    #[inline]
+    fn saturating_map_into<Dst>(self) -> SaturatingMapInto<Self, Dst>
+    where
+        Self: Sized,
+        Dst: SaturatingFrom<Self::Item>,
+    {
+        SaturatingMapInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the first `N` items into an array of `Dst`, returning [`None`] if the iterator
+    /// yields fewer than `N` items. Any additional items are ignored.
+    ///
+    /// ```
+    /// use saturate::SaturatingIterator;
+    ///
+    /// let rgba: Option<[u8; 4]> = [300, -2, 127, 256].iter().copied().saturating_into_array();
+    /// assert_eq!(Some([255, 0, 127, 255]), rgba);
+    /// ```
+    fn saturating_into_array<Dst, const N: usize>(mut self) -> Option<[Dst; N]>
+    where
+        Self: Sized,
+        Dst: SaturatingFrom<Self::Item>,
+    {
+        let items: [Option<Self::Item>; N] = core::array::from_fn(|_| self.next());
+        if items.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(items.map(|item| Dst::saturating_from(item.unwrap())))
+    }
+
+    /// Converts each item to `Dst` and returns the maximum, or [`None`] if the iterator is empty.
+    ///
+    /// Saturation preserves ordering, so this is equal to converting the maximum item.
+    ///
+    /// ```
+    /// use saturate::SaturatingIterator;
+    ///
+    /// let max: Option<u16> = [5i64, 70000, -3].iter().copied().saturating_max_into();
+    /// assert_eq!(Some(u16::MAX), max);
+    /// ```
+    fn saturating_max_into<Dst>(self) -> Option<Dst>
+    where
+        Self: Sized,
+        Dst: SaturatingFrom<Self::Item> + Ord,
+    {
+        self.map(Dst::saturating_from).max()
+    }
+
+    /// Converts each item to `Dst` and returns the minimum, or [`None`] if the iterator is empty.
+    ///
+    /// Saturation preserves ordering, so this is equal to converting the minimum item.
+    ///
+    /// ```
+    /// use saturate::SaturatingIterator;
+    ///
+    /// let min: Option<u16> = [5i64, 70000, -3].iter().copied().saturating_min_into();
+    /// assert_eq!(Some(0), min);
+    /// ```
+    fn saturating_min_into<Dst>(self) -> Option<Dst>
+    where
+        Self: Sized,
+        Dst: SaturatingFrom<Self::Item> + Ord,
+    {
+        self.map(Dst::saturating_from).min()
+    }
+}
+
+impl<I: Iterator> SaturatingIterator for I {}
+
+/// Iterator which converts each item of another iterator, see
+/// [`SaturatingIterator::saturating_map_into`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SaturatingMapInto<I, Dst> {
+    iter: I,
+    marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for SaturatingMapInto<I, Dst>
+where
+    I: Iterator,
+    Dst: SaturatingFrom<I::Item>,
+{
+    type Item = Dst;
+
+    #[inline]
+    fn next(&mut self) -> Option<Dst> {
+        self.iter.next().map(Dst::saturating_from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, Dst> DoubleEndedIterator for SaturatingMapInto<I, Dst>
+where
+    I: DoubleEndedIterator,
+    Dst: SaturatingFrom<I::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Dst> {
+        self.iter.next_back().map(Dst::saturating_from)
+    }
+}
+
+impl<I, Dst> ExactSizeIterator for SaturatingMapInto<I, Dst>
+where
+    I: ExactSizeIterator,
+    Dst: SaturatingFrom<I::Item>,
+{
+}
+
+impl<I, Dst> FusedIterator for SaturatingMapInto<I, Dst>
+where
+    I: FusedIterator,
+    Dst: SaturatingFrom<I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{NonZeroI16, NonZeroU8};
+
+    use super::*;
+
+    #[test]
+    fn saturating_into_array() {
+        let src = [-26i32, 76, 935, 255];
+        assert_eq!(
+            Some([0u8, 76, 255, 255]),
+            src.iter().copied().saturating_into_array()
+        );
+
+        let src = [1.5f64, -3.0, f64::NAN];
+        assert_eq!(
+            Some([1i16, -3]),
+            src.iter().copied().saturating_into_array()
+        );
+        assert_eq!(
+            None::<[i16; 4]>,
+            src.iter().copied().saturating_into_array()
+        );
+        assert_eq!(Some([0u8; 0]), src.iter().copied().saturating_into_array());
+        assert_eq!(
+            Some([0u8; 0]),
+            core::iter::empty::<i32>().saturating_into_array()
+        );
+    }
+
+    #[test]
+    fn saturating_max_min_into() {
+        let src = [-70000i64, 12, 65535, 65536, i64::MAX];
+        assert_eq!(
+            Some(u16::MAX),
+            src.iter().copied().saturating_max_into::<u16>()
+        );
+        assert_eq!(Some(0u16), src.iter().copied().saturating_min_into());
+        assert_eq!(
+            Some(i16::MIN),
+            src.iter().copied().saturating_min_into::<i16>()
+        );
+
+        let src = [3.7f32, f32::NAN, -1.5];
+        assert_eq!(Some(3u8), src.iter().copied().saturating_max_into());
+        assert_eq!(Some(0u8), src.iter().copied().saturating_min_into());
+        assert_eq!(Some(-1i8), src.iter().copied().saturating_min_into());
+
+        assert_eq!(
+            None::<u16>,
+            core::iter::empty::<i64>().saturating_max_into()
+        );
+        assert_eq!(
+            None::<u16>,
+            core::iter::empty::<i64>().saturating_min_into()
+        );
+    }
+
+    #[test]
+    fn saturating_map_into() {
+        let src = [-1.5f64, 70000.0, f64::NAN, 12.9];
+        let result: Vec<u16> = src.iter().copied().saturating_map_into().collect();
+        assert_eq!([0, u16::MAX, 0, 12], *result);
+
+        let mut iter = src.iter().copied().saturating_map_into::<i8>();
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(12), iter.next_back());
+        assert_eq!(Some(-1), iter.next());
+        assert_eq!(2, iter.len());
+    }
+
+    #[test]
+    fn saturating_map_into_nonzero() {
+        let result: Vec<NonZeroU8> = [0i32, 300, 7, -4]
+            .iter()
+            .copied()
+            .saturating_map_into()
+            .collect();
+        let result: Vec<u8> = result.into_iter().map(NonZeroU8::get).collect();
+        assert_eq!([1, 255, 7, 1], *result);
+
+        let result: Vec<i16> = [0u64, u64::MAX]
+            .iter()
+            .copied()
+            .saturating_map_into::<NonZeroI16>()
+            .map(NonZeroI16::get)
+            .collect();
+        assert_eq!([1, i16::MAX], *result);
+    }
+}
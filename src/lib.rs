@@ -14,6 +14,20 @@
 //! assert!(f32::saturating_from(u128::MAX).is_infinite()); // out of range => infinity
 //! assert_eq!(u8::MAX, 300.0.saturating_into());
 //! ```
+//!
+//! ## Features
+//!
+//! The minimum supported Rust version is 1.63 with default features. Optional features with higher
+//! requirements are noted below.
+//!
+//! * `portable-simd`: enables [`saturating_convert_slice_simd`], a SIMD implementation of
+//!   [`saturating_convert_slice`]. Requires a nightly compiler.
+//! * `num-saturating`: implements [`SaturatingFrom`] to and from [`core::num::Saturating`].
+//!   Requires Rust 1.74 or later.
+//! * `const-float`: adds `const fn` float to integer conversions to the [`consts`] module. Requires
+//!   Rust 1.82 or later.
+
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
 /// Trait to perform a saturating conversion between two numeric types. It is
 /// the opposite of [`SaturatingInto`].
@@ -22,6 +36,10 @@
 /// automatically provide an implementation of [`SaturatingInto`] thanks to its
 /// blanket implementation.
 pub trait SaturatingFrom<T> {
+    /// Whether every value of `T` is exactly representable as `Self`, meaning the conversion is
+    /// lossless and never saturates. Defaults to `false`.
+    const IS_WIDENING: bool = false;
+
     /// Converts the input type `T` to `Self`
     fn saturating_from(value: T) -> Self;
 }
@@ -30,6 +48,8 @@ macro_rules! impl_self {
     ($($typ:ty),+) => {
         $(
             impl SaturatingFrom<$typ> for $typ {
+                const IS_WIDENING: bool = true;
+
                 #[inline]
                 fn saturating_from(value: $typ) -> $typ {
                     value
@@ -45,6 +65,8 @@ macro_rules! impl_from {
     ([$($src:ty),+] => $dst:ty) => {
         $(
             impl SaturatingFrom<$src> for $dst {
+                const IS_WIDENING: bool = true;
+
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
                     <$dst>::from(value)
@@ -75,15 +97,17 @@ macro_rules! impl_clamp {
             impl SaturatingFrom<$src> for $dst {
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
-                    use core::convert::TryFrom;
-                    // try_from(..).unwrap() is optimised out (tested on 1.78 with opt-level=2)
-                    <$dst>::try_from(value.min(<$src>::from(<$dst>::MAX)).max(<$src>::from(<$dst>::MIN))).unwrap()
+                    // The clamped value is within the range of $dst, so the cast is exact
+                    value.clamp(<$src>::from(<$dst>::MIN), <$src>::from(<$dst>::MAX)) as $dst
                 }
             }
         )+
     };
 }
 
+// The clamp compiles to branchless code (e.g. cmov on x86-64) and auto-vectorises, which is at
+// least as fast as hand written bit tricks (see: benches/narrow_64.rs). Ord::clamp compiles to the
+// same code as an explicit min/max since the bounds are constant (see: benches/ord_clamp.rs)
 impl_clamp!([i16, u16, i32, u32, i64, u64, i128, u128] => u8);
 impl_clamp!([i32, u32, i64, u64, i128, u128] => u16);
 impl_clamp!([i64, u64, i128, u128] => u32);
@@ -100,9 +124,8 @@ macro_rules! impl_clamp_unsigned {
             impl SaturatingFrom<$src> for $dst {
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
-                    use core::convert::TryFrom;
-                    // try_from(..).unwrap() is optimised out (tested on 1.78 with opt-level=2)
-                    <$dst>::try_from(value.min(<$src>::try_from(<$dst>::MAX).unwrap())).unwrap()
+                    // The clamped value is within the range of $dst, so the cast is exact
+                    value.min(<$dst>::MAX as $src) as $dst
                 }
             }
         )+
@@ -122,9 +145,8 @@ macro_rules! impl_clamp_signed {
             impl SaturatingFrom<$src> for $dst {
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
-                    use core::convert::TryFrom;
-                    // try_from(..).unwrap() is optimised out (tested on 1.78 with opt-level=2)
-                    <$dst>::try_from(value.max(0)).unwrap()
+                    // The clamped value is within the range of $dst, so the cast is exact
+                    value.max(0) as $dst
                 }
             }
         )+
@@ -181,12 +203,16 @@ macro_rules! impl_as {
     };
 }
 
-// `as` will round to nearest (and saturate at f32::INFINITY for `u128` => f32)
+// `as` will round to nearest with ties to even, i.e. correctly rounded (and saturate at
+// f32::INFINITY for `u128` => f32). The sign of zero is preserved for float sources, and integer
+// zero always converts to +0.0
 impl_as!([i32, u32, i64, u64, i128, u128] => f32);
 impl_as!([i64, u64, i128, u128] => f64);
 impl_as!([f64] => f32);
 
-// `as` will saturate and convert NaN => 0 since 1.45 (see: rust-lang/rust#10184)
+// `as` will saturate and convert NaN => 0 since 1.45 (see: rust-lang/rust#10184). For small
+// targets this already compiles to a minimal clamp and truncation (e.g. maxss, minss, cvttss2si on
+// x86-64), which is faster than clamping manually (see: benches/float_to_u8.rs)
 impl_as!([f32, f64] => u8);
 impl_as!([f32, f64] => u16);
 impl_as!([f32, f64] => u32);
@@ -203,6 +229,8 @@ macro_rules! impl_bool_float {
     ($($dst:ty),+) => {
         $(
             impl SaturatingFrom<bool> for $dst {
+                const IS_WIDENING: bool = true;
+
                 #[inline]
                 fn saturating_from(value: bool) -> $dst {
                     <$dst>::from(u8::from(value))
@@ -218,6 +246,8 @@ macro_rules! impl_equivalent {
     ([$($src:ty as $equ:ty),+] => $dst:ty) => {
         $(
             impl SaturatingFrom<$src> for $dst {
+                const IS_WIDENING: bool = <$dst as SaturatingFrom<$equ>>::IS_WIDENING;
+
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
                     <$dst>::saturating_from(value as $equ)
@@ -228,6 +258,8 @@ macro_rules! impl_equivalent {
     ([$($src:ty),+] => $dst:ty as $equ:ty) => {
         $(
             impl SaturatingFrom<$src> for $dst {
+                const IS_WIDENING: bool = <$equ as SaturatingFrom<$src>>::IS_WIDENING;
+
                 #[inline]
                 fn saturating_from(value: $src) -> $dst {
                     <$equ>::saturating_from(value) as $dst
@@ -335,6 +367,210 @@ where
     }
 }
 
+/// Performs a saturating conversion from `Src` to `Dst`.
+///
+/// This is equivalent to [`SaturatingInto::saturating_into`], but can be passed directly to
+/// iterator adaptors with the destination type given explicitly.
+///
+/// ```
+/// let bytes: Vec<_> = vec![-26, 76, 935].into_iter().map(saturate::convert::<u8, _>).collect();
+/// assert_eq!(vec![0, 76, 255], bytes);
+/// ```
+#[inline]
+pub fn convert<Dst, Src: SaturatingInto<Dst>>(src: Src) -> Dst {
+    src.saturating_into()
+}
+
+/// Performs a saturating conversion from any type which widens losslessly to [`i128`].
+///
+/// This allows custom integer types to be converted by implementing only [`Into<i128>`], rather
+/// than [`SaturatingFrom`] for every target type. See [`saturating_from_wide_unsigned`] for
+/// unsigned sources.
+///
+/// ```
+/// struct Delta(i32);
+///
+/// impl From<Delta> for i128 {
+///     fn from(value: Delta) -> i128 {
+///         value.0.into()
+///     }
+/// }
+///
+/// assert_eq!(-128, saturate::saturating_from_wide::<i8>(Delta(-1000)));
+/// assert_eq!(0, saturate::saturating_from_wide::<u16>(Delta(-1)));
+/// ```
+#[inline]
+pub fn saturating_from_wide<Dst: SaturatingFrom<i128>>(value: impl Into<i128>) -> Dst {
+    Dst::saturating_from(value.into())
+}
+
+/// Performs a saturating conversion from any type which widens losslessly to [`u128`].
+///
+/// This is the unsigned equivalent of [`saturating_from_wide`].
+///
+/// ```
+/// struct Count(u64);
+///
+/// impl From<Count> for u128 {
+///     fn from(value: Count) -> u128 {
+///         value.0.into()
+///     }
+/// }
+///
+/// assert_eq!(255, saturate::saturating_from_wide_unsigned::<u8>(Count(1000)));
+/// assert_eq!(i64::MAX, saturate::saturating_from_wide_unsigned::<i64>(Count(u64::MAX)));
+/// ```
+#[inline]
+pub fn saturating_from_wide_unsigned<Dst: SaturatingFrom<u128>>(value: impl Into<u128>) -> Dst {
+    Dst::saturating_from(value.into())
+}
+
+/// Performs a saturating conversion from `Src` to `Dst`, then clamps the result to `min..=max`.
+///
+/// This is useful for implementing conversions into bounded types, which can't be covered by a
+/// blanket implementation of [`SaturatingFrom`]. NaN is passed through unchanged for float
+/// targets.
+///
+/// ```
+/// use saturate::SaturatingFrom;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Bounded<const MIN: u8, const MAX: u8>(u8);
+///
+/// impl<const MIN: u8, const MAX: u8> SaturatingFrom<i32> for Bounded<MIN, MAX> {
+///     fn saturating_from(value: i32) -> Self {
+///         Bounded(saturate::saturating_into_bounds(value, MIN, MAX))
+///     }
+/// }
+///
+/// assert_eq!(Bounded::<10, 200>(10), Bounded::saturating_from(-5));
+/// assert_eq!(Bounded::<10, 200>(200), Bounded::saturating_from(1000));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min > max`.
+#[inline]
+pub fn saturating_into_bounds<Dst, Src>(value: Src, min: Dst, max: Dst) -> Dst
+where
+    Dst: SaturatingFrom<Src> + PartialOrd,
+{
+    assert!(min <= max, "min is greater than max");
+    let value = Dst::saturating_from(value);
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Performs a saturating conversion from `Src` into the intersection of the ranges of `A` and `B`,
+/// returning the result as `Dst`.
+///
+/// This is useful when a value must fit more than one type, such as a protocol field and a storage
+/// field. For example, the intersection of [`i8`] and [`u8`] is `0..=127`.
+///
+/// ```
+/// let value: i32 = saturate::saturating_from_intersection::<i8, u8, _, _>(300);
+/// assert_eq!(127, value);
+/// let value: u16 = saturate::saturating_from_intersection::<i8, u8, _, _>(-5.5);
+/// assert_eq!(0, value);
+/// ```
+#[inline]
+pub fn saturating_from_intersection<A, B, Dst, Src>(value: Src) -> Dst
+where
+    A: SaturatingFrom<Src>,
+    B: SaturatingFrom<A>,
+    Dst: SaturatingFrom<B>,
+{
+    // Clamping into `A` and then into `B` gives a value in both ranges, since the intersection of
+    // two intervals containing zero is never empty
+    Dst::saturating_from(B::saturating_from(A::saturating_from(value)))
+}
+
+mod abs;
+mod audit;
+mod boolean;
+mod bounds;
+mod bytes;
+mod cell;
+mod chain;
+pub mod char;
+mod clamp;
+mod classify;
+mod compound;
+pub mod consts;
+pub mod dynamic;
+mod error;
+pub mod ffi;
+mod finite;
+mod iter;
+mod macros;
+mod mode;
+mod nan;
+mod neg;
+mod nonzero;
+mod ordering;
+mod policy;
+mod ratio;
+mod remainder;
+mod round_odd;
+mod rounding;
+#[cfg(feature = "num-saturating")]
+mod saturating;
+mod scaled;
+#[cfg(feature = "portable-simd")]
+mod simd;
+mod slice;
+mod stage;
+mod tagged;
+pub mod text;
+mod time;
+mod transparent;
+mod try_from;
+mod underflow;
+mod widening;
+mod wrapping;
+
+pub use abs::SaturatingFromAbs;
+pub use audit::{AuditResult, SaturatingFromAudited};
+pub use boolean::{saturating_bool_from_exact, SaturatingFromNonzero, SaturatingFromThreshold};
+pub use bounds::SaturatingBounds;
+pub use bytes::{saturating_from_be_slice, saturating_from_le_slice, SaturatingFromBytes};
+pub use cell::{saturating_from_cell, saturating_from_ref_cell};
+pub use chain::Saturate;
+pub use clamp::{SaturatingClamp, SaturatingFromValidated};
+pub use error::{Clamped, DecodeError, NonFinite, ParseError, RangeError};
+pub use finite::SaturatingFromFinite;
+pub use iter::{SaturatingIterator, SaturatingMapInto};
+pub use mode::{ConvertMode, SaturatingConvertWith};
+pub use nan::SaturatingFromPreserveNan;
+pub use neg::SaturatingFromNeg;
+pub use policy::{ClampPolicy, SaturatingFromOrElse, SaturatingFromWithPolicy, SaturationPolicy};
+pub use ratio::{saturating_map_range, saturating_ratio_to_float, saturating_ratio_to_int};
+pub use remainder::{SaturatingFromBitsLost, SaturatingFromWithRemainder};
+pub use round_odd::saturating_from_round_to_odd;
+pub use rounding::{
+    SaturatingFromBounds, SaturatingFromDirected, SaturatingFromRoundNearestChecked,
+    SaturatingFromTiesEven,
+};
+pub use scaled::SaturatingFromScaled;
+#[cfg(feature = "portable-simd")]
+pub use simd::{saturating_convert_slice_simd, SimdSaturatingFrom};
+pub use slice::{saturating_convert_slice, saturating_cow};
+pub use stage::{saturate_assoc, HasNumericOutput};
+pub use tagged::{SaturatingFromDir, SaturatingFromTagged};
+pub use time::{saturating_millis_since, saturating_unix_secs};
+pub use transparent::TransparentNumeric;
+pub use try_from::{SaturatingFromMode, SaturatingIntoChecked, SaturatingOrError};
+pub use underflow::saturating_from_checked_underflow;
+pub use widening::{widen, Widening};
+
+#[doc(hidden)]
+pub use macros::__private;
+
 #[allow(clippy::bool_assert_comparison)]
 #[cfg(test)]
 mod tests {
@@ -368,6 +604,223 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_widening() {
+        fn is_widening<T: SaturatingFrom<U>, U>() -> bool {
+            T::IS_WIDENING
+        }
+
+        assert!(is_widening::<u32, u16>());
+        assert!(is_widening::<i64, u32>());
+        assert!(is_widening::<i128, bool>());
+        assert!(is_widening::<f64, f32>());
+        assert!(is_widening::<f32, i16>());
+        assert!(is_widening::<f64, bool>());
+        assert!(is_widening::<u8, u8>());
+        assert!(is_widening::<f32, f32>());
+        assert!(is_widening::<i128, isize>());
+        assert!(is_widening::<usize, u8>());
+        assert!(is_widening::<isize, i8>());
+
+        assert!(!is_widening::<u16, u32>());
+        assert!(!is_widening::<u32, i32>());
+        assert!(!is_widening::<i32, u32>());
+        assert!(!is_widening::<f32, i32>());
+        assert!(!is_widening::<f32, f64>());
+        assert!(!is_widening::<i64, f32>());
+        assert!(!is_widening::<u8, f32>());
+        assert!(!is_widening::<bool, u8>());
+        assert!(!is_widening::<usize, isize>());
+        assert!(!is_widening::<u8, usize>());
+        assert!(!is_widening::<usize, u128>());
+        assert!(is_widening::<u128, usize>());
+        assert!(!is_widening::<i8, isize>());
+
+        assert_eq!(
+            is_widening::<u64, usize>(),
+            cfg!(target_pointer_width = "64")
+        );
+        assert!(is_widening::<Result<u32, ()>, Result<u16, ()>>());
+        assert!(!is_widening::<Result<u8, ()>, Result<u16, ()>>());
+    }
+
+    #[test]
+    fn impl_equivalent_float() {
+        #[cfg(target_pointer_width = "16")]
+        {
+            assert_eq!(65535.0f32, f32::saturating_from(usize::MAX));
+            assert_eq!(65535.0f64, f64::saturating_from(usize::MAX));
+            assert_eq!(32767.0f32, f32::saturating_from(isize::MAX));
+            assert_eq!(-32768.0f64, f64::saturating_from(isize::MIN));
+
+            assert_eq!(usize::MAX, usize::saturating_from(65535.0f32));
+            assert_eq!(usize::MAX, usize::saturating_from(65536.0f64));
+            assert_eq!(isize::MIN, isize::saturating_from(-32768.5f32));
+            assert_eq!(isize::MIN, isize::saturating_from(-32769.0f64));
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        {
+            // u32::MAX rounds up to 2^32 in f32 but is exact in f64
+            assert_eq!(4294967296.0f32, f32::saturating_from(usize::MAX));
+            assert_eq!(4294967295.0f64, f64::saturating_from(usize::MAX));
+            assert_eq!(2147483648.0f32, f32::saturating_from(isize::MAX));
+            assert_eq!(2147483647.0f64, f64::saturating_from(isize::MAX));
+            assert_eq!(-2147483648.0f32, f32::saturating_from(isize::MIN));
+
+            assert_eq!(usize::MAX, usize::saturating_from(4294967296.0f32));
+            assert_eq!(usize::MAX, usize::saturating_from(4294967295.0f64));
+            assert_eq!(isize::MAX, isize::saturating_from(2147483648.0f32));
+            assert_eq!(isize::MIN, isize::saturating_from(-2147483648.5f64));
+        }
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            // The maximums round up to the next power of two in both f32 and f64
+            assert_eq!(18446744073709551616.0f32, f32::saturating_from(usize::MAX));
+            assert_eq!(18446744073709551616.0f64, f64::saturating_from(usize::MAX));
+            assert_eq!(9223372036854775808.0f32, f32::saturating_from(isize::MAX));
+            assert_eq!(-9223372036854775808.0f64, f64::saturating_from(isize::MIN));
+
+            assert_eq!(
+                usize::MAX,
+                usize::saturating_from(18446744073709551616.0f32)
+            );
+            assert_eq!(usize::MAX, usize::saturating_from(f64::MAX));
+            assert_eq!(isize::MAX, isize::saturating_from(9223372036854775808.0f64));
+            assert_eq!(
+                isize::MIN,
+                isize::saturating_from(-9223372036854775808.0f32)
+            );
+        }
+
+        // On every width the results match the fixed width equivalent
+        macro_rules! check_equivalent {
+            ($($src:ident as $equ:ty),+) => {$(
+                for &value in &[$src::MIN, $src::MIN + 1, 0, 1, $src::MAX - 1, $src::MAX] {
+                    let equ = value as $equ;
+                    assert_eq!(f32::saturating_from(equ), f32::saturating_from(value));
+                    assert_eq!(f64::saturating_from(equ), f64::saturating_from(value));
+                }
+            )+};
+        }
+
+        #[cfg(target_pointer_width = "16")]
+        check_equivalent!(usize as u16, isize as i16);
+        #[cfg(target_pointer_width = "32")]
+        check_equivalent!(usize as u32, isize as i32);
+        #[cfg(target_pointer_width = "64")]
+        check_equivalent!(usize as u64, isize as i64);
+
+        assert_eq!(0usize, usize::saturating_from(-1.0f32));
+        assert_eq!(0usize, usize::saturating_from(f64::NAN));
+        assert_eq!(0isize, isize::saturating_from(f32::NAN));
+    }
+
+    #[test]
+    fn convert() {
+        let src = [-1.5f64, 0.5, 300.0, f64::NAN];
+        let result: Vec<_> = src.iter().copied().map(super::convert::<u8, _>).collect();
+        assert_eq!(vec![0, 0, 255, 0], result);
+
+        let sum: i64 = [u64::MAX, 5]
+            .iter()
+            .copied()
+            .map(super::convert::<i32, _>)
+            .map(i64::from)
+            .sum();
+        assert_eq!(i64::from(i32::MAX) + 5, sum);
+    }
+
+    #[test]
+    fn saturating_into_bounds() {
+        assert_eq!(10u8, super::saturating_into_bounds(-5i32, 10, 200));
+        assert_eq!(10u8, super::saturating_into_bounds(10i32, 10, 200));
+        assert_eq!(150u8, super::saturating_into_bounds(150i32, 10, 200));
+        assert_eq!(200u8, super::saturating_into_bounds(200i32, 10, 200));
+        assert_eq!(200u8, super::saturating_into_bounds(1000i32, 10, 200));
+        assert_eq!(200u8, super::saturating_into_bounds(f64::INFINITY, 10, 200));
+        assert_eq!(10u8, super::saturating_into_bounds(f64::NAN, 10, 200));
+        assert_eq!(-3i8, super::saturating_into_bounds(u64::MIN, -10, -3));
+        assert_is_close!(0.5f32, super::saturating_into_bounds(7u8, 0.0, 0.5));
+        assert!(super::saturating_into_bounds::<f32, _>(f64::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn saturating_into_bounds_invalid() {
+        super::saturating_into_bounds::<u8, _>(5i32, 200, 10);
+    }
+
+    #[test]
+    fn saturating_from_intersection() {
+        fn i16_and_u8(value: i32) -> i32 {
+            super::saturating_from_intersection::<i16, u8, _, _>(value)
+        }
+        assert_eq!(0, i16_and_u8(-40000));
+        assert_eq!(0, i16_and_u8(-1));
+        assert_eq!(200, i16_and_u8(200));
+        assert_eq!(255, i16_and_u8(256));
+        assert_eq!(255, i16_and_u8(40000));
+
+        // The order of the types doesn't matter
+        fn u8_and_i16(value: i32) -> i32 {
+            super::saturating_from_intersection::<u8, i16, _, _>(value)
+        }
+        assert_eq!(0, u8_and_i16(-40000));
+        assert_eq!(255, u8_and_i16(40000));
+
+        assert_eq!(
+            127u8,
+            super::saturating_from_intersection::<i8, u8, _, _>(300i32)
+        );
+        assert_eq!(
+            0i64,
+            super::saturating_from_intersection::<i8, u8, _, _>(-300i32)
+        );
+        assert_eq!(
+            -128i16,
+            super::saturating_from_intersection::<i8, i32, _, _>(-1e10f64)
+        );
+        assert_eq!(
+            0u32,
+            super::saturating_from_intersection::<i8, u8, _, _>(f32::NAN)
+        );
+    }
+
+    #[test]
+    fn saturating_from_wide() {
+        #[derive(Clone, Copy)]
+        struct Int24(i32);
+
+        impl From<Int24> for i128 {
+            fn from(value: Int24) -> i128 {
+                i128::from(value.0)
+            }
+        }
+
+        impl From<Int24> for u128 {
+            fn from(value: Int24) -> u128 {
+                // Stand-in for an unsigned type; negative values are clamped beforehand
+                value.0.max(0) as u128
+            }
+        }
+
+        assert_eq!(-128i8, super::saturating_from_wide(Int24(-0x80_0000)));
+        assert_eq!(127i8, super::saturating_from_wide(Int24(0x7f_ffff)));
+        assert_eq!(-300i16, super::saturating_from_wide(Int24(-300)));
+        assert_eq!(0x7f_ffffi64, super::saturating_from_wide(Int24(0x7f_ffff)));
+        assert_eq!(0u32, super::saturating_from_wide(Int24(-1)));
+
+        assert_eq!(255u8, super::saturating_from_wide_unsigned(Int24(256)));
+        assert_eq!(
+            0x7fffi16,
+            super::saturating_from_wide_unsigned(Int24(0x7f_ffff))
+        );
+        assert_eq!(0u64, super::saturating_from_wide_unsigned(Int24(-5)));
+        assert_eq!(42i32, super::saturating_from_wide_unsigned(42u8));
+    }
+
     #[test]
     fn impl_self() {
         assert_eq!(true, bool::saturating_from(true));
@@ -405,6 +858,29 @@ mod tests {
         assert_eq!(-12i8, i8::saturating_from(-12i64));
     }
 
+    #[test]
+    fn impl_clamp_64bit_boundaries() {
+        let bounds = [
+            i64::MIN,
+            i64::from(i32::MIN),
+            0,
+            i64::from(i32::MAX),
+            i64::from(u32::MAX),
+            i64::MAX,
+        ];
+        for value in bounds
+            .iter()
+            .flat_map(|&b| (-2..=2).map(move |d| b.saturating_add(d)))
+        {
+            let expected = value.max(i64::from(i32::MIN)).min(i64::from(i32::MAX));
+            assert_eq!(expected, i64::from(i32::saturating_from(value)));
+
+            let value = value as u64;
+            let expected = value.min(u64::from(u32::MAX));
+            assert_eq!(expected, u64::from(u32::saturating_from(value)));
+        }
+    }
+
     #[test]
     fn impl_clamp_unsigned() {
         assert_eq!(0x7fi8, i8::saturating_from(60954u16));
@@ -412,6 +888,43 @@ mod tests {
         assert_eq!(62879i32, i32::saturating_from(62879u128));
     }
 
+    #[test]
+    fn impl_clamp_128bit_boundaries() {
+        use core::convert::TryFrom;
+
+        macro_rules! check_boundaries {
+            ($($dst:ty),+) => {
+                $(
+                    // i128 sources
+                    let min = i128::from(<$dst>::MIN);
+                    let max = i128::from(<$dst>::MAX);
+                    assert_eq!(<$dst>::MIN, <$dst>::saturating_from(i128::MIN));
+                    assert_eq!(<$dst>::MIN, <$dst>::saturating_from(i128::MIN + 1));
+                    assert_eq!(<$dst>::MIN, <$dst>::saturating_from(min - 1));
+                    assert_eq!(<$dst>::MIN, <$dst>::saturating_from(min));
+                    assert_eq!(<$dst>::MIN + 1, <$dst>::saturating_from(min + 1));
+                    assert_eq!(<$dst>::MAX - 1, <$dst>::saturating_from(max - 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(max));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(max + 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(i128::MAX - 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(i128::MAX));
+
+                    // u128 sources
+                    let max = u128::try_from(<$dst>::MAX).unwrap();
+                    assert_eq!(0 as $dst, <$dst>::saturating_from(u128::MIN));
+                    assert_eq!(1 as $dst, <$dst>::saturating_from(u128::MIN + 1));
+                    assert_eq!(<$dst>::MAX - 1, <$dst>::saturating_from(max - 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(max));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(max + 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(u128::MAX - 1));
+                    assert_eq!(<$dst>::MAX, <$dst>::saturating_from(u128::MAX));
+                )+
+            };
+        }
+
+        check_boundaries!(i8, u8, i16, u16);
+    }
+
     #[test]
     fn impl_clamp_signed() {
         assert_eq!(0u8, u8::saturating_from(-12i8));
@@ -456,6 +969,267 @@ mod tests {
         assert_eq!(0i32, i32::saturating_from(f64::NAN));
     }
 
+    #[test]
+    fn nan_payloads() {
+        // Quiet and signaling NaNs of both signs, with minimal and maximal payloads
+        let nan32 = [
+            0x7fc0_0000u32, // quiet
+            0xffc0_0000,    // negative quiet
+            0x7f80_0001,    // signaling
+            0xff80_0001,    // negative signaling
+            0x7fa0_0000,
+            0x7fbf_ffff,
+            0x7fff_ffff,
+            0xffff_ffff,
+        ];
+        let nan64 = [
+            0x7ff8_0000_0000_0000u64, // quiet
+            0xfff8_0000_0000_0000,    // negative quiet
+            0x7ff0_0000_0000_0001,    // signaling
+            0xfff0_0000_0000_0001,    // negative signaling
+            0x7ff4_0000_0000_0000,
+            0x7ff7_ffff_ffff_ffff,
+            0x7fff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+        ];
+
+        macro_rules! check_nan {
+            ($($dst:ty),+) => {$(
+                for &bits in &nan32 {
+                    let value = f32::from_bits(bits);
+                    assert!(value.is_nan());
+                    assert_eq!(0 as $dst, <$dst>::saturating_from(value), "{:#x}", bits);
+                }
+                for &bits in &nan64 {
+                    let value = f64::from_bits(bits);
+                    assert!(value.is_nan());
+                    assert_eq!(0 as $dst, <$dst>::saturating_from(value), "{:#x}", bits);
+                }
+            )+};
+        }
+
+        check_nan!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+        for &bits in &nan32 {
+            assert!(!bool::saturating_from(f32::from_bits(bits)));
+        }
+        for &bits in &nan64 {
+            assert!(!bool::saturating_from(f64::from_bits(bits)));
+        }
+    }
+
+    // Reference round-to-nearest-even of `value` to `precision` significant bits, returned as
+    // (significand, shift) such that the rounded value is `significand << shift`
+    fn reference_round(value: u128, precision: u32) -> (u128, u32) {
+        let width = 128 - value.leading_zeros();
+        if width <= precision {
+            return (value, 0);
+        }
+        let shift = width - precision;
+        let (mut sig, rem, half) = (value >> shift, value & ((1 << shift) - 1), 1 << (shift - 1));
+        if rem > half || (rem == half && sig & 1 == 1) {
+            sig += 1;
+        }
+        if sig >> precision != 0 {
+            (sig >> 1, shift + 1) // rounding carried into a new bit
+        } else {
+            (sig, shift)
+        }
+    }
+
+    fn reference_f32(value: u128) -> f32 {
+        let (sig, shift) = reference_round(value, 24);
+        match 128 - sig.leading_zeros() + shift {
+            0 => 0.0,
+            129..=u32::MAX => f32::INFINITY,
+            // sig < 2^24 is exact, multiplying by a power of two is exact below the overflow
+            _ => sig as f32 * f32::from_bits((127 + shift) << 23),
+        }
+    }
+
+    fn reference_f64(value: u128) -> f64 {
+        let (sig, shift) = reference_round(value, 53);
+        // sig < 2^53 is exact, u128::MAX is far below f64::MAX so no overflow is possible
+        sig as f64 * f64::from_bits(u64::from(1023 + shift) << 52)
+    }
+
+    fn reference_signed<F: core::ops::Neg<Output = F>>(value: i128, conv: fn(u128) -> F) -> F {
+        if value < 0 {
+            -conv((value as u128).wrapping_neg())
+        } else {
+            conv(value as u128)
+        }
+    }
+
+    #[test]
+    fn impl_as_128bit_reference() {
+        let mut unsigned = vec![0, 1, u128::MAX, i128::MAX as u128, u128::MAX - 1];
+        for &exp in &[23, 24, 25, 52, 53, 54, 63, 64, 65, 96, 104, 105, 126, 127] {
+            let pow = 1u128 << exp;
+            unsigned.extend_from_slice(&[pow - 1, pow, pow + 1, pow + 2, pow + 3]);
+            // values straddling the halfway point between adjacent f32/f64 values
+            for &precision in &[24, 53] {
+                if exp > precision {
+                    let half_ulp = 1u128 << (exp - precision);
+                    let odd = pow + 2 * half_ulp; // odd significand, so ties round up
+                    unsigned.extend_from_slice(&[pow + half_ulp - 1, pow + half_ulp]);
+                    unsigned.extend_from_slice(&[pow + half_ulp + 1, odd + half_ulp]);
+                }
+            }
+        }
+
+        for &value in &unsigned {
+            assert_eq!(
+                reference_f32(value).to_bits(),
+                f32::saturating_from(value).to_bits()
+            );
+            assert_eq!(
+                reference_f64(value).to_bits(),
+                f64::saturating_from(value).to_bits()
+            );
+        }
+
+        let mut signed = vec![i128::MIN, i128::MIN + 1, i128::MAX, -1];
+        for &value in &unsigned {
+            if value <= i128::MAX as u128 {
+                signed.extend_from_slice(&[value as i128, -(value as i128)]);
+            }
+        }
+
+        for &value in &signed {
+            let expected = reference_signed(value, reference_f32);
+            assert_eq!(expected.to_bits(), f32::saturating_from(value).to_bits());
+            let expected = reference_signed(value, reference_f64);
+            assert_eq!(expected.to_bits(), f64::saturating_from(value).to_bits());
+        }
+    }
+
+    #[test]
+    fn u128_to_float_correctly_rounded() {
+        // Known results, independent of the reference implementation. Ties between adjacent f64
+        // values round to the even significand
+        let pow = 1u128 << 64;
+        let ulp = 1u128 << 12; // ulp of f64 in [2^64, 2^65)
+        assert_eq!(18446744073709551616.0, f64::saturating_from(pow + ulp / 2));
+        assert_eq!(
+            18446744073709555712.0,
+            f64::saturating_from(pow + ulp / 2 + 1)
+        );
+        assert_eq!(
+            18446744073709559808.0,
+            f64::saturating_from(pow + 3 * ulp / 2)
+        );
+        assert_eq!(18446744073709551616.0, f64::saturating_from(pow - ulp / 4));
+        assert_eq!(
+            18446744073709549568.0,
+            f64::saturating_from(pow - ulp / 4 - 1)
+        );
+        assert_eq!(
+            340282366920938463463374607431768211456.0,
+            f64::saturating_from(u128::MAX)
+        );
+        assert_eq!(f32::INFINITY, f32::saturating_from(u128::MAX));
+
+        // Values straddling the halfway points either side of each power of two, where the ulp
+        // below is half the ulp above
+        let mut values = vec![u128::MAX, u128::MAX - 1];
+        for &exp in &[54, 63, 64, 65, 95, 96, 97, 126, 127] {
+            let pow = 1u128 << exp;
+            for &precision in &[24, 53] {
+                let above = 1u128 << (exp + 1 - precision);
+                let below = above / 2;
+                for &mid in &[pow - below / 2, pow + above / 2, pow + above + above / 2] {
+                    values.extend_from_slice(&[mid - 1, mid, mid + 1]);
+                }
+            }
+        }
+        // Values straddling the halfway points just below u128::MAX, which round up to 2^128
+        for &precision in &[24, 53] {
+            let ulp = 1u128 << (128 - precision);
+            let mid = u128::MAX - ulp / 2 + 1;
+            values.extend_from_slice(&[mid - 1, mid, mid + 1]);
+        }
+        // Pseudo-random values of every bit width
+        let mut state = 0x2545_f491_4f6c_dd1du128;
+        for width in 1..=128 {
+            for _ in 0..16 {
+                state = state
+                    .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+                    .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+                values.push(state >> (128 - width));
+            }
+        }
+
+        for &value in &values {
+            assert_eq!(
+                reference_f64(value).to_bits(),
+                f64::saturating_from(value).to_bits(),
+                "{}",
+                value
+            );
+            assert_eq!(
+                reference_f32(value).to_bits(),
+                f32::saturating_from(value).to_bits(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn impl_as_u128_f32_overflow() {
+        let max = f32::MAX as u128; // (2^24 - 1) * 2^104
+        let ulp = 1u128 << 104;
+        let below_max = f32::from_bits(f32::MAX.to_bits() - 1);
+
+        assert_eq!(max, ((1 << 24) - 1) << 104);
+        assert_eq!(f32::MAX, f32::saturating_from(max));
+        assert_eq!(f32::MAX, f32::saturating_from(max + 1));
+        assert_eq!(f32::MAX, f32::saturating_from(max + ulp / 2 - 1));
+        // Halfway to 2^128 rounds to even, i.e. up to 2^128 which overflows to infinity
+        assert_eq!(f32::INFINITY, f32::saturating_from(max + ulp / 2));
+        assert_eq!(f32::INFINITY, f32::saturating_from(max + ulp / 2 + 1));
+        assert_eq!(f32::INFINITY, f32::saturating_from(u128::MAX - 1));
+        assert_eq!(f32::INFINITY, f32::saturating_from(u128::MAX));
+
+        // Halfway below f32::MAX rounds to even, i.e. down to the next lower f32
+        assert_eq!(below_max, f32::saturating_from(max - ulp));
+        assert_eq!(below_max, f32::saturating_from(max - ulp / 2));
+        assert_eq!(f32::MAX, f32::saturating_from(max - ulp / 2 + 1));
+        assert_eq!(f32::MAX, f32::saturating_from(max - 1));
+
+        // Sweep the whole region between the two highest f32 values and infinity
+        let step = ulp / 64 - 1;
+        let mut value = max - ulp;
+        while let Some(next) = value.checked_add(step) {
+            assert_eq!(reference_f32(value), f32::saturating_from(value));
+            value = next;
+        }
+    }
+
+    #[test]
+    fn signed_zero() {
+        macro_rules! check_zero {
+            ([$($src:ty),+] => $dst:ty) => {$(
+                assert!(<$dst>::saturating_from(<$src>::saturating_from(0u8)).is_sign_positive());
+            )+};
+        }
+
+        check_zero!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f32);
+        check_zero!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f64);
+
+        assert!(f32::saturating_from(-0.0f64).is_sign_negative());
+        assert!(f64::saturating_from(-0.0f32).is_sign_negative());
+        assert!(f32::saturating_from(-0.0f32).is_sign_negative());
+        assert!(f64::saturating_from(-0.0f64).is_sign_negative());
+        assert!(f32::saturating_from(0.0f64).is_sign_positive());
+        assert!(f64::saturating_from(0.0f32).is_sign_positive());
+
+        // Underflow to zero keeps the sign of the source
+        assert!(f32::saturating_from(-1e-60f64).is_sign_negative());
+        assert!(f32::saturating_from(1e-60f64).is_sign_positive());
+    }
+
     #[test]
     fn impl_bool_float() {
         assert_eq!(1.0f32, f32::saturating_from(true));
@@ -0,0 +1,302 @@
+/// Performs a saturating conversion using syntax similar to an `as` cast.
+///
+/// `saturating_convert!(expr as Type)` is equivalent to
+/// `<Type as SaturatingFrom<_>>::saturating_from(expr)`. Conversions can be chained, with each
+/// step saturating in turn.
+///
+/// Unlike `as`, the conversion applies to the entire expression to its left, so
+/// `saturating_convert!(a + b as u8)` converts the result of `a + b`.
+///
+/// ```
+/// use saturate::saturating_convert;
+///
+/// let x = 300i32;
+/// assert_eq!(255, saturating_convert!(x as u8));
+/// assert_eq!(127, saturating_convert!(x - 5 as i8));
+/// assert_eq!(2, saturating_convert!(2.7f64 as i32 as u8));
+/// assert_eq!(510u16, saturating_convert!(x as u8) as u16 * 2);
+/// ```
+#[macro_export]
+macro_rules! saturating_convert {
+    (@munch [$($value:tt)+] as $typ:ty) => {
+        $crate::SaturatingInto::<$typ>::saturating_into($($value)+)
+    };
+    (@munch [$($value:tt)+] as $typ:ty as $($rest:tt)+) => {
+        $crate::saturating_convert!(
+            @munch [$crate::SaturatingInto::<$typ>::saturating_into($($value)+)] as $($rest)+
+        )
+    };
+    (@munch [$($value:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::saturating_convert!(@munch [$($value)* $next] $($rest)*)
+    };
+    ($($tokens:tt)+) => {
+        $crate::saturating_convert!(@munch [] $($tokens)+)
+    };
+}
+
+/// Implements [`SaturatingFrom`](crate::SaturatingFrom) for a list of pairs of custom numeric
+/// types.
+///
+/// Each `Src => Dst` pair generates `impl SaturatingFrom<Src> for Dst`. The value is converted
+/// with [`TryFrom`] if it is in range, and otherwise clamped to the nearest bound of `Dst`. Each
+/// pair requires that:
+///
+/// * `Dst` implements [`TryFrom<Src>`](TryFrom), failing only if the value is out of range, and
+///   [`SaturatingBounds`](crate::SaturatingBounds).
+/// * `Src` implements [`TryFrom<Dst>`](TryFrom) (or [`From<Dst>`](From)), [`PartialOrd`], and
+///   [`Copy`].
+///
+/// Like the primitive conversions, values are compared with the bounds of `Dst` in the domain of
+/// `Src`, so `Src` must be able to represent any bound of `Dst` it can exceed.
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use saturate::{impl_conversions, SaturatingBounds, SaturatingFrom};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// struct Celsius(i32);
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// struct Reading(i8);
+///
+/// impl TryFrom<Celsius> for Reading {
+///     type Error = core::num::TryFromIntError;
+///     fn try_from(value: Celsius) -> Result<Self, Self::Error> {
+///         i8::try_from(value.0).map(Reading)
+///     }
+/// }
+///
+/// impl From<Reading> for Celsius {
+///     fn from(value: Reading) -> Self {
+///         Celsius(value.0.into())
+///     }
+/// }
+///
+/// impl SaturatingBounds for Reading {
+///     fn saturating_min() -> Self {
+///         Reading(i8::MIN)
+///     }
+///     fn saturating_max() -> Self {
+///         Reading(i8::MAX)
+///     }
+/// }
+///
+/// impl_conversions! { Celsius => Reading }
+///
+/// assert_eq!(Reading(21), Reading::saturating_from(Celsius(21)));
+/// assert_eq!(Reading(127), Reading::saturating_from(Celsius(1000)));
+/// assert_eq!(Reading(-128), Reading::saturating_from(Celsius(-1000)));
+/// ```
+#[macro_export]
+macro_rules! impl_conversions {
+    ($($src:ty => $dst:ty),+ $(,)?) => {
+        $(
+            impl $crate::SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    use ::core::convert::TryFrom;
+                    match <$dst as TryFrom<$src>>::try_from(value) {
+                        Ok(result) => result,
+                        Err(_) => {
+                            let max = <$dst as $crate::SaturatingBounds>::saturating_max();
+                            // If the maximum is out of range of Src the value cannot exceed it
+                            match <$src as TryFrom<$dst>>::try_from(max) {
+                                Ok(bound) if value > bound => max,
+                                _ => <$dst as $crate::SaturatingBounds>::saturating_min(),
+                            }
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// Performs a saturating conversion if one is implemented, or returns [`Default::default`]
+/// otherwise.
+///
+/// `try_saturate_or_default!(Type, expr)` is equivalent to
+/// `<Type as SaturatingFrom<_>>::saturating_from(expr)` if `Type` implements
+/// [`SaturatingFrom`](crate::SaturatingFrom) for the type of `expr`, and to
+/// `{ drop(expr); Type::default() }` otherwise.
+///
+/// ```
+/// use saturate::try_saturate_or_default;
+///
+/// assert_eq!(255u8, try_saturate_or_default!(u8, 300i32));
+/// assert_eq!(0u8, try_saturate_or_default!(u8, "300"));
+/// ```
+///
+/// Stable Rust has no specialization, so this is implemented with autoref-based method resolution
+/// and can only be a macro rather than a function. The choice is made where the macro is
+/// expanded, using the types known at that point. Within a generic function the conversion is only
+/// used if it is required by the function's bounds; otherwise the default is always returned, even
+/// if the function is later called with a pair of types that can be converted:
+///
+/// ```
+/// use saturate::{try_saturate_or_default, SaturatingFrom};
+///
+/// fn unbounded<T>(value: T) -> u8 {
+///     try_saturate_or_default!(u8, value)
+/// }
+///
+/// fn bounded<T>(value: T) -> u8
+/// where
+///     u8: SaturatingFrom<T>,
+/// {
+///     try_saturate_or_default!(u8, value)
+/// }
+///
+/// assert_eq!(0, unbounded(300i32));
+/// assert_eq!(255, bounded(300i32));
+/// ```
+#[macro_export]
+macro_rules! try_saturate_or_default {
+    ($typ:ty, $value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{ViaDefault as _, ViaSaturatingFrom as _};
+        (&$crate::__private::OrDefault::<_, $typ>::new($value)).convert()
+    }};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use core::cell::Cell;
+    use core::marker::PhantomData;
+
+    use crate::SaturatingFrom;
+
+    // Holds the value to convert for try_saturate_or_default. The conversion methods take &self
+    // so the value is held in a Cell to move it out
+    pub struct OrDefault<Src, Dst>(Cell<Option<Src>>, PhantomData<Dst>);
+
+    impl<Src, Dst> OrDefault<Src, Dst> {
+        #[inline]
+        pub fn new(value: Src) -> Self {
+            OrDefault(Cell::new(Some(value)), PhantomData)
+        }
+    }
+
+    // Implemented for OrDefault, so it is found first when resolving a method call on &OrDefault
+    pub trait ViaSaturatingFrom<Dst> {
+        fn convert(&self) -> Dst;
+    }
+
+    impl<Src, Dst: SaturatingFrom<Src>> ViaSaturatingFrom<Dst> for OrDefault<Src, Dst> {
+        #[inline]
+        fn convert(&self) -> Dst {
+            match self.0.take() {
+                Some(value) => Dst::saturating_from(value),
+                None => unreachable!("value converted twice"),
+            }
+        }
+    }
+
+    // Implemented for &OrDefault, so it is only found after autoref if the above doesn't apply
+    pub trait ViaDefault<Dst> {
+        fn convert(&self) -> Dst;
+    }
+
+    impl<Src, Dst: Default> ViaDefault<Dst> for &OrDefault<Src, Dst> {
+        #[inline]
+        fn convert(&self) -> Dst {
+            Dst::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use core::num::TryFromIntError;
+
+    use crate::{SaturatingBounds, SaturatingFrom};
+
+    #[test]
+    fn saturating_convert() {
+        let x = -1.5e10f64;
+        assert_eq!(i32::MIN, saturating_convert!(x as i32));
+        assert_eq!(0u8, saturating_convert!(x as u8));
+        assert_eq!(0u8, saturating_convert!(x as i64 as u8));
+        assert_eq!(u64::MAX, saturating_convert!(-x * 1e10 as u64));
+        assert_eq!(200u8, saturating_convert!((100 + 100) as u8));
+        assert_eq!(255u8, saturating_convert!(100i32 + 200 as u8));
+
+        let values = [300i64, -300];
+        assert_eq!(127i8, saturating_convert!(values[0] as i8));
+        assert_eq!(
+            -128i8,
+            saturating_convert!(values.iter().sum::<i64>() - 300 as i8)
+        );
+        assert_eq!(-256i32, i32::from(saturating_convert!(values[1] as i8)) * 2);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Millis(i64);
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Level(u8);
+
+    impl TryFrom<Millis> for Level {
+        type Error = TryFromIntError;
+        fn try_from(value: Millis) -> Result<Self, Self::Error> {
+            u8::try_from(value.0).map(Level)
+        }
+    }
+
+    impl From<Level> for Millis {
+        fn from(value: Level) -> Self {
+            Millis(value.0.into())
+        }
+    }
+
+    impl SaturatingBounds for Level {
+        fn saturating_min() -> Self {
+            Level(0)
+        }
+        fn saturating_max() -> Self {
+            Level(u8::MAX)
+        }
+    }
+
+    impl SaturatingBounds for Millis {
+        fn saturating_min() -> Self {
+            Millis(i64::MIN)
+        }
+        fn saturating_max() -> Self {
+            Millis(i64::MAX)
+        }
+    }
+
+    impl_conversions! {
+        Millis => Level,
+        Level => Millis,
+    }
+
+    #[test]
+    fn impl_conversions() {
+        assert_eq!(Level(42), Level::saturating_from(Millis(42)));
+        assert_eq!(Level(255), Level::saturating_from(Millis(255)));
+        assert_eq!(Level(255), Level::saturating_from(Millis(256)));
+        assert_eq!(Level(255), Level::saturating_from(Millis(i64::MAX)));
+        assert_eq!(Level(0), Level::saturating_from(Millis(-1)));
+        assert_eq!(Level(0), Level::saturating_from(Millis(i64::MIN)));
+
+        assert_eq!(Millis(0), Millis::saturating_from(Level(0)));
+        assert_eq!(Millis(255), Millis::saturating_from(Level(255)));
+    }
+
+    #[test]
+    fn try_saturate_or_default() {
+        assert_eq!(255u8, try_saturate_or_default!(u8, 300i32));
+        assert_eq!(-1i64, try_saturate_or_default!(i64, -1.5f32));
+        assert_eq!(Level(255), try_saturate_or_default!(Level, Millis(1000)));
+
+        #[derive(Debug, Default, PartialEq)]
+        struct Unsupported(u8);
+
+        assert_eq!(0u8, try_saturate_or_default!(u8, "42"));
+        assert_eq!(Unsupported(0), try_saturate_or_default!(Unsupported, 42u8));
+        assert_eq!(0u32, try_saturate_or_default!(u32, String::from("42")));
+    }
+}
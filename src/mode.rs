@@ -0,0 +1,92 @@
+use crate::SaturatingFrom;
+
+/// How [`SaturatingConvertWith`] handles values which are out of range of the target type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConvertMode {
+    /// Clamp the value to the target's range, as [`SaturatingFrom`]
+    Saturate,
+    /// Keep the low bits of the value, as an `as` cast
+    Wrap,
+}
+
+/// Trait to perform a conversion between integer types where the handling of out of range values
+/// is selected at the call site.
+///
+/// This allows code which mixes `as` casts and saturating conversions to use a single entry point.
+/// Unlike [`SaturatingFromMode`](crate::SaturatingFromMode), the mode is a runtime value.
+///
+/// ```
+/// use saturate::{ConvertMode, SaturatingConvertWith};
+///
+/// assert_eq!(0, u8::convert_with(256i32, ConvertMode::Wrap));
+/// assert_eq!(255, u8::convert_with(256i32, ConvertMode::Saturate));
+/// ```
+pub trait SaturatingConvertWith<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, either saturating or wrapping depending on `mode`
+    fn convert_with(value: T, mode: ConvertMode) -> Self;
+}
+
+macro_rules! impl_convert_with {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingConvertWith<$src> for $dst {
+                #[inline]
+                fn convert_with(value: $src, mode: ConvertMode) -> $dst {
+                    match mode {
+                        ConvertMode::Saturate => <$dst>::saturating_from(value),
+                        ConvertMode::Wrap => value as $dst,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u8);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u16);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u32);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u64);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u128);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => usize);
+
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i8);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i16);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i32);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i64);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i128);
+impl_convert_with!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap() {
+        assert_eq!(0u8, u8::convert_with(256i32, ConvertMode::Wrap));
+        assert_eq!(255u8, u8::convert_with(-1i64, ConvertMode::Wrap));
+        assert_eq!(-1i8, i8::convert_with(255u8, ConvertMode::Wrap));
+        assert_eq!(u32::MAX, u32::convert_with(-1i8, ConvertMode::Wrap));
+        assert_eq!(i64::MIN, i64::convert_with(1u128 << 63, ConvertMode::Wrap));
+    }
+
+    #[test]
+    fn saturate() {
+        assert_eq!(255u8, u8::convert_with(256i32, ConvertMode::Saturate));
+        assert_eq!(0u8, u8::convert_with(-1i64, ConvertMode::Saturate));
+        assert_eq!(127i8, i8::convert_with(255u8, ConvertMode::Saturate));
+        assert_eq!(0u32, u32::convert_with(-1i8, ConvertMode::Saturate));
+        assert_eq!(
+            i64::MAX,
+            i64::convert_with(1u128 << 63, ConvertMode::Saturate)
+        );
+    }
+
+    #[test]
+    fn in_range() {
+        for &mode in &[ConvertMode::Saturate, ConvertMode::Wrap] {
+            assert_eq!(100u8, u8::convert_with(100i32, mode));
+            assert_eq!(-5i16, i16::convert_with(-5i128, mode));
+            assert_eq!(u64::MAX, u64::convert_with(u64::MAX, mode));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion between floating point types, preserving the sign
+/// and payload bits of NaN values.
+///
+/// The result of converting NaN with `as` (and so [`SaturatingFrom`]) has an unspecified bit
+/// pattern. With this trait the sign, quiet bit, and payload are kept when widening. When
+/// narrowing from [`f64`] to [`f32`], the payload can't be fully preserved so only its high bits
+/// are kept. If none of those bits are set, the lowest bit of the result's payload is set to keep
+/// the value NaN.
+///
+/// Non-NaN values are converted exactly as with [`SaturatingFrom`].
+pub trait SaturatingFromPreserveNan<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, preserving NaN payloads
+    fn saturating_from_preserve_nan(value: T) -> Self;
+}
+
+impl SaturatingFromPreserveNan<f64> for f32 {
+    #[inline]
+    fn saturating_from_preserve_nan(value: f64) -> f32 {
+        if !value.is_nan() {
+            return f32::saturating_from(value);
+        }
+        let bits = value.to_bits();
+        let sign = (bits >> 32) as u32 & 0x8000_0000;
+        let payload = (bits >> 29) as u32 & 0x007f_ffff;
+        let payload = if payload == 0 { 1 } else { payload };
+        f32::from_bits(sign | 0x7f80_0000 | payload)
+    }
+}
+
+impl SaturatingFromPreserveNan<f32> for f64 {
+    #[inline]
+    fn saturating_from_preserve_nan(value: f32) -> f64 {
+        if !value.is_nan() {
+            return f64::saturating_from(value);
+        }
+        let bits = u64::from(value.to_bits());
+        let sign = (bits & 0x8000_0000) << 32;
+        let payload = (bits & 0x007f_ffff) << 29;
+        f64::from_bits(sign | 0x7ff0_0000_0000_0000 | payload)
+    }
+}
+
+macro_rules! impl_preserve_nan_self {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingFromPreserveNan<$typ> for $typ {
+                #[inline]
+                fn saturating_from_preserve_nan(value: $typ) -> $typ {
+                    value
+                }
+            }
+        )+
+    };
+}
+
+impl_preserve_nan_self!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUIET_F64: u64 = 0x0008_0000_0000_0000;
+    const QUIET_F32: u32 = 0x0040_0000;
+
+    #[test]
+    fn narrow() {
+        // quiet NaN with payload in the high bits
+        let nan = f64::from_bits(0x7ff0_0000_0000_0000 | QUIET_F64 | (0x12345 << 29) | 0x7f);
+        let result = f32::saturating_from_preserve_nan(nan).to_bits();
+        assert_eq!(0x7f80_0000 | QUIET_F32 | 0x12345, result);
+
+        // signalling NaN with a negative sign
+        let nan = f64::from_bits(0xfff0_0000_0000_0000 | 0x0001_0000_0000_0000);
+        let result = f32::saturating_from_preserve_nan(nan);
+        assert!(result.is_nan() && result.is_sign_negative());
+        assert_eq!(0, result.to_bits() & QUIET_F32);
+        assert_eq!(0x0008_0000, result.to_bits() & 0x003f_ffff);
+
+        // signalling NaN with only low payload bits set stays NaN
+        let nan = f64::from_bits(0x7ff0_0000_0000_0001);
+        let result = f32::saturating_from_preserve_nan(nan);
+        assert_eq!(0x7f80_0001, result.to_bits());
+
+        assert_eq!(1.5f32, f32::saturating_from_preserve_nan(1.5f64));
+        assert!(f32::saturating_from_preserve_nan(1e40f64).is_infinite());
+    }
+
+    #[test]
+    fn widen() {
+        let nan = f32::from_bits(0xff80_0000 | 0x0000_1234);
+        let result = f64::saturating_from_preserve_nan(nan).to_bits();
+        assert_eq!(0xfff0_0000_0000_0000 | (0x1234 << 29), result);
+        assert_eq!(
+            nan.to_bits(),
+            f32::saturating_from_preserve_nan(f64::from_bits(result)).to_bits()
+        );
+
+        let nan = f32::from_bits(0x7f80_0000 | QUIET_F32 | 0x55);
+        let result = f64::saturating_from_preserve_nan(nan).to_bits();
+        assert_eq!(QUIET_F64, result & QUIET_F64);
+        assert_eq!(
+            nan.to_bits(),
+            f32::saturating_from_preserve_nan(f64::from_bits(result)).to_bits()
+        );
+
+        assert_eq!(-2.25f64, f64::saturating_from_preserve_nan(-2.25f32));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion of the negation of an integer into a signed integer
+/// type.
+///
+/// Unlike negating after converting, the negation is computed before the value is clamped, so
+/// large magnitudes saturate to `MIN` rather than `MIN + 1`. Negating a signed `MIN` also cannot
+/// overflow, and saturates to `MAX` if the result is out of range.
+///
+/// ```
+/// use saturate::SaturatingFromNeg;
+///
+/// assert_eq!(-100, i8::saturating_neg_from(100u8));
+/// assert_eq!(-128, i8::saturating_neg_from(200u8));
+/// assert_eq!(127, i8::saturating_neg_from(i8::MIN));
+/// assert_eq!(128, i16::saturating_neg_from(i8::MIN));
+/// ```
+pub trait SaturatingFromNeg<T>: SaturatingFrom<T> + Sized {
+    /// Converts the negation of the input type `T` to `Self`
+    fn saturating_neg_from(value: T) -> Self;
+}
+
+macro_rules! impl_neg_unsigned {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromNeg<$src> for $dst {
+                #[inline]
+                fn saturating_neg_from(value: $src) -> $dst {
+                    let value = value as u128;
+                    if value > 1 << 127 {
+                        <$dst>::MIN
+                    } else {
+                        // 2^127 wraps to i128::MIN, which is also its negation
+                        <$dst>::saturating_from((value as i128).wrapping_neg())
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_neg_signed {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromNeg<$src> for $dst {
+                #[inline]
+                fn saturating_neg_from(value: $src) -> $dst {
+                    match (value as i128).checked_neg() {
+                        Some(value) => <$dst>::saturating_from(value),
+                        // -i128::MIN is greater than the maximum of every target type
+                        None => <$dst>::MAX,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => i8);
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => i16);
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => i32);
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => i64);
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => i128);
+impl_neg_unsigned!([u8, u16, u32, u64, u128, usize] => isize);
+
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => i8);
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => i16);
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => i32);
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => i64);
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => i128);
+impl_neg_signed!([i8, i16, i32, i64, i128, isize] => isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(-100i8, i8::saturating_neg_from(100u8));
+        assert_eq!(-128i8, i8::saturating_neg_from(128u8));
+        assert_eq!(-128i8, i8::saturating_neg_from(129u8));
+        assert_eq!(-128i8, i8::saturating_neg_from(200u8));
+        assert_eq!(i16::MIN, i16::saturating_neg_from(u64::MAX));
+        assert_eq!(-(1i64 << 32), i64::saturating_neg_from(1u64 << 32));
+        assert_eq!(i128::MIN, i128::saturating_neg_from(1u128 << 127));
+        assert_eq!(i128::MIN, i128::saturating_neg_from((1u128 << 127) + 1));
+        assert_eq!(i128::MIN, i128::saturating_neg_from(u128::MAX));
+        assert_eq!(0isize, isize::saturating_neg_from(0usize));
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(5i8, i8::saturating_neg_from(-5i8));
+        assert_eq!(-127i8, i8::saturating_neg_from(i8::MAX));
+        assert_eq!(i8::MAX, i8::saturating_neg_from(i8::MIN));
+        assert_eq!(128i16, i16::saturating_neg_from(i8::MIN));
+        assert_eq!(i32::MAX, i32::saturating_neg_from(i32::MIN));
+        assert_eq!(i8::MIN, i8::saturating_neg_from(1000i64));
+        assert_eq!(i64::MAX, i64::saturating_neg_from(i128::MIN));
+        assert_eq!(i128::MAX, i128::saturating_neg_from(i128::MIN));
+        assert_eq!(-i128::MAX, i128::saturating_neg_from(i128::MAX));
+        assert_eq!(0i8, i8::saturating_neg_from(0isize));
+    }
+}
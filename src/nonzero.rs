@@ -0,0 +1,331 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::SaturatingFrom;
+
+macro_rules! impl_nonzero {
+    ([$($src:ident as $src_prim:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                const IS_WIDENING: bool = <$dst_prim as SaturatingFrom<$src_prim>>::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    let value = <$dst_prim>::saturating_from(value.get());
+                    // SAFETY: the source is nonzero, and saturating conversion between integers
+                    // never maps a nonzero value to zero unless a negative value is converted to
+                    // an unsigned type, which is handled by impl_nonzero_signed instead
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_nonzero_signed {
+    ([$($src:ident as $src_prim:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    // Negative values saturate to the minimum nonzero value, i.e. one
+                    let value = <$dst_prim>::saturating_from(value.get()).max(1);
+                    // SAFETY: value is at least one
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_nonzero_all {
+    ($($dst:ident as $dst_prim:ty),+) => {
+        $(
+            impl_nonzero!([
+                NonZeroU8 as u8, NonZeroU16 as u16, NonZeroU32 as u32, NonZeroU64 as u64,
+                NonZeroU128 as u128, NonZeroUsize as usize, NonZeroI8 as i8, NonZeroI16 as i16,
+                NonZeroI32 as i32, NonZeroI64 as i64, NonZeroI128 as i128, NonZeroIsize as isize
+            ] => $dst as $dst_prim);
+        )+
+    };
+}
+
+macro_rules! impl_nonzero_unsigned {
+    ($($dst:ident as $dst_prim:ty),+) => {
+        $(
+            impl_nonzero!([
+                NonZeroU8 as u8, NonZeroU16 as u16, NonZeroU32 as u32, NonZeroU64 as u64,
+                NonZeroU128 as u128, NonZeroUsize as usize
+            ] => $dst as $dst_prim);
+            impl_nonzero_signed!([
+                NonZeroI8 as i8, NonZeroI16 as i16, NonZeroI32 as i32, NonZeroI64 as i64,
+                NonZeroI128 as i128, NonZeroIsize as isize
+            ] => $dst as $dst_prim);
+        )+
+    };
+}
+
+impl_nonzero_unsigned!(
+    NonZeroU8 as u8,
+    NonZeroU16 as u16,
+    NonZeroU32 as u32,
+    NonZeroU64 as u64,
+    NonZeroU128 as u128,
+    NonZeroUsize as usize
+);
+
+impl_nonzero_all!(
+    NonZeroI8 as i8,
+    NonZeroI16 as i16,
+    NonZeroI32 as i32,
+    NonZeroI64 as i64,
+    NonZeroI128 as i128,
+    NonZeroIsize as isize
+);
+
+macro_rules! impl_nonzero_float_unsigned {
+    ([$($src:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            /// Values which would convert to zero, including negative values and NaN, saturate to
+            /// the minimum nonzero value, i.e. one
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    let value = <$dst_prim>::saturating_from(value).max(1);
+                    // SAFETY: value is at least one
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_nonzero_float_signed {
+    ([$($src:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            /// Values which would convert to zero saturate to one or minus one depending on their
+            /// sign. NaN has no meaningful sign, so it converts to one
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    let value = match <$dst_prim>::saturating_from(value) {
+                        0 if value < 0.0 => -1,
+                        0 => 1,
+                        value => value,
+                    };
+                    // SAFETY: value is nonzero
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroU8 as u8);
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroU16 as u16);
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroU32 as u32);
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroU64 as u64);
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroU128 as u128);
+impl_nonzero_float_unsigned!([f32, f64] => NonZeroUsize as usize);
+
+impl_nonzero_float_signed!([f32, f64] => NonZeroI8 as i8);
+impl_nonzero_float_signed!([f32, f64] => NonZeroI16 as i16);
+impl_nonzero_float_signed!([f32, f64] => NonZeroI32 as i32);
+impl_nonzero_float_signed!([f32, f64] => NonZeroI64 as i64);
+impl_nonzero_float_signed!([f32, f64] => NonZeroI128 as i128);
+impl_nonzero_float_signed!([f32, f64] => NonZeroIsize as isize);
+
+macro_rules! impl_nonzero_int_unsigned {
+    ([$($src:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            /// Values which would convert to zero, including negative values and `false`,
+            /// saturate to the minimum nonzero value, i.e. one
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    let value = <$dst_prim>::saturating_from(value).max(1);
+                    // SAFETY: value is at least one
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_nonzero_int_signed {
+    ([$($src:ty),+] => $dst:ident as $dst_prim:ty) => {
+        $(
+            /// Zero and `false` have no sign, so they saturate to one
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> $dst {
+                    let value = match <$dst_prim>::saturating_from(value) {
+                        0 => 1,
+                        value => value,
+                    };
+                    // SAFETY: value is nonzero
+                    unsafe { <$dst>::new_unchecked(value) }
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroU8 as u8);
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroU16 as u16);
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroU32 as u32);
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroU64 as u64);
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroU128 as u128);
+impl_nonzero_int_unsigned!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroUsize as usize);
+
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroI8 as i8);
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroI16 as i16);
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroI32 as i32);
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroI64 as i64);
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroI128 as i128);
+impl_nonzero_int_signed!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => NonZeroIsize as isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingInto;
+
+    #[test]
+    fn has_impl() {
+        fn has_impl_inner<T: SaturatingFrom<U>, U: SaturatingInto<T>>() {}
+
+        macro_rules! check_impls {
+            (@inner [$src:ty], [$($dst:ty),+]) => {$(
+                has_impl_inner::<$dst, $src>();
+            )*};
+            (@inner [$($src:ty),+], $dst:tt) => {$(
+                check_impls!(@inner [$src], $dst);
+            )*};
+            ($($typ:ty),+) => {
+                check_impls!(@inner [$($typ),+], [$($typ),+]);
+            };
+        }
+
+        // Will fail to compile if any permutation is not implemented
+        check_impls!(
+            NonZeroU8,
+            NonZeroU16,
+            NonZeroU32,
+            NonZeroU64,
+            NonZeroU128,
+            NonZeroUsize,
+            NonZeroI8,
+            NonZeroI16,
+            NonZeroI32,
+            NonZeroI64,
+            NonZeroI128,
+            NonZeroIsize
+        );
+    }
+
+    #[test]
+    fn has_impl_primitive() {
+        fn has_impl_inner<T: SaturatingFrom<U>, U: SaturatingInto<T>>() {}
+
+        macro_rules! check_impls {
+            (@inner [$src:ty], [$($dst:ty),+]) => {$(
+                has_impl_inner::<$dst, $src>();
+            )*};
+            ([$($src:ty),+] => $dst:tt) => {$(
+                check_impls!(@inner [$src], $dst);
+            )*};
+        }
+
+        // Will fail to compile if any NonZero type is not reachable from any primitive
+        check_impls!(
+            [bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64] => [
+                NonZeroU8,
+                NonZeroU16,
+                NonZeroU32,
+                NonZeroU64,
+                NonZeroU128,
+                NonZeroUsize,
+                NonZeroI8,
+                NonZeroI16,
+                NonZeroI32,
+                NonZeroI64,
+                NonZeroI128,
+                NonZeroIsize
+            ]
+        );
+    }
+
+    #[test]
+    fn impl_nonzero() {
+        let value = NonZeroU32::new(300).unwrap();
+        assert_eq!(255, NonZeroU8::saturating_from(value).get());
+        assert_eq!(300, NonZeroU64::saturating_from(value).get());
+        assert_eq!(127, NonZeroI8::saturating_from(value).get());
+
+        let value = NonZeroI64::new(-300).unwrap();
+        assert_eq!(-128, NonZeroI8::saturating_from(value).get());
+        assert_eq!(-300, NonZeroI128::saturating_from(value).get());
+        assert_eq!(-300, NonZeroIsize::saturating_from(value).get());
+
+        let value = NonZeroU128::new(u128::MAX).unwrap();
+        assert_eq!(i128::MAX, NonZeroI128::saturating_from(value).get());
+        assert_eq!(usize::MAX, NonZeroUsize::saturating_from(value).get());
+    }
+
+    #[test]
+    fn impl_nonzero_signed() {
+        let value = NonZeroI32::new(-5).unwrap();
+        assert_eq!(1, NonZeroU8::saturating_from(value).get());
+        assert_eq!(1, NonZeroUsize::saturating_from(value).get());
+
+        let value = NonZeroI8::new(i8::MIN).unwrap();
+        assert_eq!(1, NonZeroU128::saturating_from(value).get());
+
+        let value = NonZeroI16::new(1000).unwrap();
+        assert_eq!(255, NonZeroU8::saturating_from(value).get());
+        assert_eq!(1000, NonZeroU32::saturating_from(value).get());
+    }
+
+    #[test]
+    fn impl_nonzero_float() {
+        assert_eq!(1, NonZeroU8::saturating_from(0.4f64).get());
+        assert_eq!(255, NonZeroU8::saturating_from(255.9f64).get());
+        assert_eq!(255, NonZeroU8::saturating_from(1e10f32).get());
+        assert_eq!(1, NonZeroU8::saturating_from(-3.0f64).get());
+        assert_eq!(1, NonZeroU8::saturating_from(f64::NAN).get());
+        assert_eq!(7, NonZeroU32::saturating_from(7.9f32).get());
+        assert_eq!(1, NonZeroU64::saturating_from(f32::NEG_INFINITY).get());
+
+        assert_eq!(1, NonZeroI16::saturating_from(0.4f32).get());
+        assert_eq!(-1, NonZeroI16::saturating_from(-0.4f32).get());
+        assert_eq!(1, NonZeroI16::saturating_from(-0.0f32).get());
+        assert_eq!(-3, NonZeroI16::saturating_from(-3.7f64).get());
+        assert_eq!(i16::MIN, NonZeroI16::saturating_from(-1e10f64).get());
+        assert_eq!(1, NonZeroI64::saturating_from(f64::NAN).get());
+    }
+
+    #[test]
+    fn impl_nonzero_int() {
+        assert_eq!(1, NonZeroU8::saturating_from(0i32).get());
+        assert_eq!(1, NonZeroU8::saturating_from(-5i64).get());
+        assert_eq!(42, NonZeroU8::saturating_from(42u128).get());
+        assert_eq!(255, NonZeroU8::saturating_from(300u16).get());
+        assert_eq!(u64::MAX, NonZeroU64::saturating_from(i128::MAX).get());
+
+        assert_eq!(1, NonZeroI8::saturating_from(0u8).get());
+        assert_eq!(-5, NonZeroI8::saturating_from(-5i64).get());
+        assert_eq!(i8::MIN, NonZeroI8::saturating_from(-300i32).get());
+        assert_eq!(i8::MAX, NonZeroI8::saturating_from(u64::MAX).get());
+        assert_eq!(1, NonZeroIsize::saturating_from(0usize).get());
+    }
+
+    #[test]
+    fn impl_nonzero_bool() {
+        assert_eq!(1, NonZeroU8::saturating_from(false).get());
+        assert_eq!(1, NonZeroU8::saturating_from(true).get());
+        assert_eq!(1, NonZeroI128::saturating_from(false).get());
+        assert_eq!(1, NonZeroIsize::saturating_from(true).get());
+    }
+}
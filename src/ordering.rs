@@ -0,0 +1,81 @@
+use core::cmp::Ordering;
+
+use crate::SaturatingFrom;
+
+macro_rules! impl_to_ordering {
+    ($($src:ty),+) => {
+        $(
+            /// Converts negative values to [`Ordering::Less`], zero to [`Ordering::Equal`], and
+            /// positive values to [`Ordering::Greater`]
+            impl SaturatingFrom<$src> for Ordering {
+                #[inline]
+                fn saturating_from(value: $src) -> Ordering {
+                    value.cmp(&0)
+                }
+            }
+        )+
+    };
+}
+
+impl_to_ordering!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_from_ordering {
+    ($($dst:ty),+) => {
+        $(
+            /// Converts [`Ordering::Less`] to -1 (or 0 for unsigned types), [`Ordering::Equal`] to
+            /// 0, and [`Ordering::Greater`] to 1
+            impl SaturatingFrom<Ordering> for $dst {
+                #[inline]
+                fn saturating_from(value: Ordering) -> $dst {
+                    <$dst>::saturating_from(value as i8)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_ordering!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+impl SaturatingFrom<Ordering> for Ordering {
+    const IS_WIDENING: bool = true;
+
+    #[inline]
+    fn saturating_from(value: Ordering) -> Ordering {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_to_ordering() {
+        assert_eq!(Ordering::Less, Ordering::saturating_from(-1i8));
+        assert_eq!(Ordering::Equal, Ordering::saturating_from(0i8));
+        assert_eq!(Ordering::Greater, Ordering::saturating_from(1i8));
+
+        assert_eq!(Ordering::Less, Ordering::saturating_from(i128::MIN));
+        assert_eq!(Ordering::Greater, Ordering::saturating_from(1_000_000i32));
+        assert_eq!(Ordering::Greater, Ordering::saturating_from(u64::MAX));
+        assert_eq!(Ordering::Equal, Ordering::saturating_from(0usize));
+    }
+
+    #[test]
+    fn impl_from_ordering() {
+        assert_eq!(-1i8, i8::saturating_from(Ordering::Less));
+        assert_eq!(0i8, i8::saturating_from(Ordering::Equal));
+        assert_eq!(1i8, i8::saturating_from(Ordering::Greater));
+
+        assert_eq!(-1i64, i64::saturating_from(Ordering::Less));
+        assert_eq!(0u8, u8::saturating_from(Ordering::Less));
+        assert_eq!(1u128, u128::saturating_from(Ordering::Greater));
+
+        for &ordering in &[Ordering::Less, Ordering::Equal, Ordering::Greater] {
+            assert_eq!(
+                ordering,
+                Ordering::saturating_from(i32::saturating_from(ordering))
+            );
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::classify::{Classify, Outcome};
+use crate::error::RangeError;
+use crate::SaturatingFrom;
+
+/// Trait for customising how out of range values are handled by
+/// [`SaturatingFromWithPolicy`].
+///
+/// Each hook receives the saturated value which would normally be returned, and returns the
+/// value to use instead. All hooks default to returning the saturated value unchanged. Hooks
+/// take `&mut self` so policies can keep state, such as a count of clamped values.
+pub trait SaturationPolicy<T> {
+    /// Called when the value is greater than the target's maximum
+    #[inline]
+    fn on_overflow(&mut self, saturated: T) -> T {
+        saturated
+    }
+
+    /// Called when the value is less than the target's minimum
+    #[inline]
+    fn on_underflow(&mut self, saturated: T) -> T {
+        saturated
+    }
+
+    /// Called when the value is NaN
+    #[inline]
+    fn on_nan(&mut self, saturated: T) -> T {
+        saturated
+    }
+}
+
+/// The default [`SaturationPolicy`], which reproduces the behaviour of [`SaturatingFrom`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClampPolicy;
+
+impl<T> SaturationPolicy<T> for ClampPolicy {}
+
+/// Trait to perform a saturating conversion to an integer type with a custom
+/// [`SaturationPolicy`] for out of range values.
+///
+/// ```
+/// use saturate::{SaturatingFromWithPolicy, SaturationPolicy};
+///
+/// struct Sentinel;
+///
+/// impl SaturationPolicy<u8> for Sentinel {
+///     fn on_overflow(&mut self, _: u8) -> u8 {
+///         0xfe
+///     }
+/// }
+///
+/// assert_eq!(0xfe, u8::saturating_from_with_policy(300, &mut Sentinel));
+/// assert_eq!(0, u8::saturating_from_with_policy(-1, &mut Sentinel));
+/// ```
+pub trait SaturatingFromWithPolicy<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, calling the corresponding hook of `policy` if the
+    /// value is out of range
+    fn saturating_from_with_policy<P>(value: T, policy: &mut P) -> Self
+    where
+        P: SaturationPolicy<Self> + ?Sized;
+}
+
+impl<T, U: Classify<T>> SaturatingFromWithPolicy<T> for U {
+    #[inline]
+    fn saturating_from_with_policy<P>(value: T, policy: &mut P) -> Self
+    where
+        P: SaturationPolicy<Self> + ?Sized,
+    {
+        match U::classify(value) {
+            (result, Outcome::Exact | Outcome::Rounded) => result,
+            (result, Outcome::Overflow) => policy.on_overflow(result),
+            (result, Outcome::Underflow) => policy.on_underflow(result),
+            (result, Outcome::Nan) => policy.on_nan(result),
+        }
+    }
+}
+
+/// Trait to perform a saturating conversion to an integer type, substituting the result of a
+/// closure for out of range values.
+///
+/// This is a lighter alternative to [`SaturatingFromWithPolicy`] for a single conversion. The
+/// closure is only called if `value` is out of range or NaN, and receives the original value.
+///
+/// ```
+/// use saturate::{RangeError, SaturatingFromOrElse};
+///
+/// let sentinel = |_: RangeError<i32>| 0xfe;
+/// assert_eq!(0xfe, u8::saturating_from_or_else(300, sentinel));
+/// assert_eq!(42, u8::saturating_from_or_else(42, sentinel));
+/// ```
+pub trait SaturatingFromOrElse<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, calling `f` instead if `value` is out of range
+    fn saturating_from_or_else<F>(value: T, f: F) -> Self
+    where
+        F: FnOnce(RangeError<T>) -> Self;
+}
+
+impl<T: Copy, U: Classify<T>> SaturatingFromOrElse<T> for U {
+    #[inline]
+    fn saturating_from_or_else<F>(value: T, f: F) -> Self
+    where
+        F: FnOnce(RangeError<T>) -> Self,
+    {
+        match U::classify(value) {
+            (result, Outcome::Exact | Outcome::Rounded) => result,
+            (_, Outcome::Overflow) => f(RangeError::AboveMax(value)),
+            (_, Outcome::Underflow) => f(RangeError::BelowMin(value)),
+            (_, Outcome::Nan) => f(RangeError::Nan(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Counter {
+        overflow: usize,
+        underflow: usize,
+        nan: usize,
+    }
+
+    impl<T> SaturationPolicy<T> for Counter {
+        fn on_overflow(&mut self, saturated: T) -> T {
+            self.overflow += 1;
+            saturated
+        }
+
+        fn on_underflow(&mut self, saturated: T) -> T {
+            self.underflow += 1;
+            saturated
+        }
+
+        fn on_nan(&mut self, saturated: T) -> T {
+            self.nan += 1;
+            saturated
+        }
+    }
+
+    struct Sentinel(i16);
+
+    impl SaturationPolicy<i16> for Sentinel {
+        fn on_overflow(&mut self, _: i16) -> i16 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn clamp_policy() {
+        assert_eq!(
+            255u8,
+            u8::saturating_from_with_policy(300i32, &mut ClampPolicy)
+        );
+        assert_eq!(
+            0u8,
+            u8::saturating_from_with_policy(-3.0f64, &mut ClampPolicy)
+        );
+        assert_eq!(
+            0i32,
+            i32::saturating_from_with_policy(f32::NAN, &mut ClampPolicy)
+        );
+        assert_eq!(
+            17i64,
+            i64::saturating_from_with_policy(17u128, &mut ClampPolicy)
+        );
+    }
+
+    #[test]
+    fn custom_policy() {
+        let mut sentinel = Sentinel(-1);
+        assert_eq!(
+            -1,
+            i16::saturating_from_with_policy(40000u32, &mut sentinel)
+        );
+        assert_eq!(-1, i16::saturating_from_with_policy(1e10f32, &mut sentinel));
+        assert_eq!(
+            -0x8000,
+            i16::saturating_from_with_policy(-1e10f32, &mut sentinel)
+        );
+        assert_eq!(
+            1234,
+            i16::saturating_from_with_policy(1234i64, &mut sentinel)
+        );
+
+        let mut counter = Counter::default();
+        let policy: &mut dyn SaturationPolicy<u16> = &mut counter;
+        u16::saturating_from_with_policy(70000i32, policy);
+        u16::saturating_from_with_policy(-5i8, policy);
+        u16::saturating_from_with_policy(-5.0f32, policy);
+        u16::saturating_from_with_policy(f64::NAN, policy);
+        u16::saturating_from_with_policy(5.5f64, policy);
+        assert_eq!(
+            (1, 2, 1),
+            (counter.overflow, counter.underflow, counter.nan)
+        );
+    }
+
+    #[test]
+    fn or_else() {
+        assert_eq!(
+            -1i16,
+            i16::saturating_from_or_else(40000u32, |error| {
+                assert_eq!(RangeError::AboveMax(40000), error);
+                -1
+            })
+        );
+        assert_eq!(
+            7u8,
+            u8::saturating_from_or_else(-3.5f64, |error| {
+                assert_eq!(RangeError::BelowMin(-3.5), error);
+                7
+            })
+        );
+        assert_eq!(
+            9i32,
+            i32::saturating_from_or_else(f32::NAN, |error| {
+                assert!(error.into_value().is_nan());
+                9
+            })
+        );
+
+        // In range values, including those which are only rounded, never call the fallback
+        fn never<T, U>(_: RangeError<T>) -> U {
+            panic!("closure called for in range value")
+        }
+        assert_eq!(1234i16, i16::saturating_from_or_else(1234i64, never));
+        assert_eq!(255u8, u8::saturating_from_or_else(255.9f32, never));
+        assert_eq!(i8::MIN, i8::saturating_from_or_else(i8::MIN, never));
+    }
+}
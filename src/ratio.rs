@@ -0,0 +1,206 @@
+use crate::{SaturatingBounds, SaturatingFrom};
+
+/// Divides `num` by `den`, truncating toward zero, and converts the quotient to `Dst`.
+///
+/// The division is performed with 128-bit intermediates so it can't overflow. Division by zero
+/// saturates to [`Dst::saturating_max`](SaturatingBounds::saturating_max) or
+/// [`Dst::saturating_min`](SaturatingBounds::saturating_min) depending on the sign of `num` (i.e.
+/// an infinity for float targets), and `0 / 0` converts to zero, consistent with the handling of
+/// NaN.
+///
+/// ```
+/// use saturate::saturating_ratio_to_int;
+///
+/// assert_eq!(3, saturating_ratio_to_int::<u8>(7, 2));
+/// assert_eq!(255, saturating_ratio_to_int::<u8>(1000, 3));
+/// assert_eq!(i32::MIN, saturating_ratio_to_int::<i32>(-1, 0));
+/// ```
+#[inline]
+pub fn saturating_ratio_to_int<Dst>(num: i64, den: i64) -> Dst
+where
+    Dst: SaturatingFrom<i128> + SaturatingBounds,
+{
+    match (num, den) {
+        (0, 0) => Dst::saturating_from(0i128),
+        (1.., 0) => Dst::saturating_max(),
+        (_, 0) => Dst::saturating_min(),
+        _ => Dst::saturating_from(i128::from(num) / i128::from(den)),
+    }
+}
+
+/// Divides `num` by `den` and converts the quotient to the floating point type `Dst`.
+///
+/// Division by zero returns infinity with the sign of `num`, and `0 / 0` returns NaN, following
+/// IEEE 754. Very large values of `num` or `den` are rounded before dividing, so the result may
+/// differ from the exact quotient by more than half an ulp.
+///
+/// ```
+/// use saturate::saturating_ratio_to_float;
+///
+/// assert_eq!(0.75, saturating_ratio_to_float::<f32>(3, 4));
+/// assert_eq!(f64::NEG_INFINITY, saturating_ratio_to_float::<f64>(-1, 0));
+/// ```
+#[inline]
+pub fn saturating_ratio_to_float<Dst: SaturatingFrom<f64>>(num: i64, den: i64) -> Dst {
+    Dst::saturating_from(f64::saturating_from(num) / f64::saturating_from(den))
+}
+
+/// Linearly maps `value` from the range `in_min..=in_max` to the range `out_min..=out_max`,
+/// truncating toward `out_min`.
+///
+/// Values outside of the input range are clamped to it first, so the result is always within the
+/// output range. Either range may be reversed (e.g. `in_min > in_max`), and if the input range is
+/// empty (`in_min == in_max`) the result is `out_min`. The calculation uses 128-bit intermediates
+/// so it can't overflow, falling back to [`f64`] for ranges too wide for the product to fit.
+/// Values of `Src` beyond the range of [`i128`] saturate before mapping.
+///
+/// ```
+/// use saturate::saturating_map_range;
+///
+/// // 10-bit ADC reading to a byte
+/// assert_eq!(127u8, saturating_map_range(512u16, 0, 1023, 0, 255));
+/// assert_eq!(255u8, saturating_map_range(2000u16, 0, 1023, 0, 255));
+/// assert_eq!(-50i8, saturating_map_range(75, 50, 100, 0, -100));
+/// ```
+pub fn saturating_map_range<Src, Dst>(
+    value: Src,
+    in_min: Src,
+    in_max: Src,
+    out_min: Dst,
+    out_max: Dst,
+) -> Dst
+where
+    i128: SaturatingFrom<Src> + SaturatingFrom<Dst>,
+    Dst: SaturatingFrom<i128>,
+{
+    let (value, in_min, in_max) = (
+        i128::saturating_from(value),
+        i128::saturating_from(in_min),
+        i128::saturating_from(in_max),
+    );
+    if in_min == in_max {
+        return out_min;
+    }
+    let (out_min, out_max) = (
+        i128::saturating_from(out_min),
+        i128::saturating_from(out_max),
+    );
+    let value = value.max(in_min.min(in_max)).min(in_min.max(in_max));
+
+    let exact = value
+        .checked_sub(in_min)
+        .zip(out_max.checked_sub(out_min))
+        .and_then(|(offset, out_span)| offset.checked_mul(out_span))
+        .zip(in_max.checked_sub(in_min))
+        .map(|(scaled, in_span)| out_min + scaled / in_span);
+    let result = exact.unwrap_or_else(|| {
+        // Each difference rounds to f64 so the result may be off by a small relative error, which
+        // must not push it out of the output range
+        let (value, in_min, in_max) = (value as f64, in_min as f64, in_max as f64);
+        let (out_min_f, out_max_f) = (out_min as f64, out_max as f64);
+        let offset = (value - in_min) * ((out_max_f - out_min_f) / (in_max - in_min));
+        i128::saturating_from(out_min_f + offset.trunc())
+            .max(out_min.min(out_max))
+            .min(out_min.max(out_max))
+    });
+    Dst::saturating_from(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_to_int() {
+        assert_eq!(3u8, saturating_ratio_to_int(7, 2));
+        assert_eq!(-3i8, saturating_ratio_to_int(-7, 2));
+        assert_eq!(-3i8, saturating_ratio_to_int(7, -2));
+        assert_eq!(0u8, saturating_ratio_to_int(-7, 2));
+        assert_eq!(0i16, saturating_ratio_to_int(1, i64::MAX));
+
+        // Quotient overflow
+        assert_eq!(255u8, saturating_ratio_to_int(1000, 3));
+        assert_eq!(i16::MIN, saturating_ratio_to_int(-1_000_000, 3));
+        assert_eq!(i64::MAX, saturating_ratio_to_int(i64::MIN, -1));
+        assert_eq!(1u128 << 63, saturating_ratio_to_int(i64::MIN, -1));
+
+        // Division by zero
+        assert_eq!(u32::MAX, saturating_ratio_to_int(5, 0));
+        assert_eq!(0u32, saturating_ratio_to_int(-5, 0));
+        assert_eq!(i64::MIN, saturating_ratio_to_int(-5, 0));
+        assert_eq!(0i64, saturating_ratio_to_int(0, 0));
+        assert_eq!(i128::MAX, saturating_ratio_to_int(5, 0));
+        assert_eq!(i128::MIN, saturating_ratio_to_int(-5, 0));
+        assert_eq!(u128::MAX, saturating_ratio_to_int(5, 0));
+        assert_eq!(0u128, saturating_ratio_to_int(-5, 0));
+        assert_eq!(f32::INFINITY, saturating_ratio_to_int(5, 0));
+        assert_eq!(f64::NEG_INFINITY, saturating_ratio_to_int(-5, 0));
+        assert_eq!(0.0f64, saturating_ratio_to_int(0, 0));
+        assert_eq!(-2.0f32, saturating_ratio_to_int(-7, 3));
+    }
+
+    #[test]
+    fn ratio_to_float() {
+        assert_eq!(0.75f32, saturating_ratio_to_float(3, 4));
+        assert_eq!(-0.5f64, saturating_ratio_to_float(1, -2));
+        assert_eq!(
+            9.223372036854776e18f64,
+            saturating_ratio_to_float(i64::MIN, -1)
+        );
+        assert_eq!(1.0f32, saturating_ratio_to_float(i64::MAX, i64::MAX));
+
+        // Division by zero
+        assert_eq!(f32::INFINITY, saturating_ratio_to_float(5, 0));
+        assert_eq!(f64::NEG_INFINITY, saturating_ratio_to_float(-5, 0));
+        assert!(saturating_ratio_to_float::<f64>(0, 0).is_nan());
+    }
+
+    #[test]
+    fn map_range() {
+        assert_eq!(0u8, saturating_map_range(0u16, 0, 1023, 0, 255));
+        assert_eq!(127u8, saturating_map_range(512u16, 0, 1023, 0, 255));
+        assert_eq!(255u8, saturating_map_range(1023u16, 0, 1023, 0, 255));
+        for value in 0..=1023u16 {
+            let expected = u32::from(value) * 255 / 1023;
+            assert_eq!(
+                expected,
+                u32::from(saturating_map_range::<_, u8>(value, 0, 1023, 0, 255))
+            );
+        }
+
+        // Percentages, including negative and reversed ranges
+        assert_eq!(50i32, saturating_map_range(0i8, -100, 100, 0, 100));
+        assert_eq!(-25i32, saturating_map_range(25u8, 0, 100, 0, -100));
+        assert_eq!(75i32, saturating_map_range(25u8, 100, 0, 0, 100));
+    }
+
+    #[test]
+    fn map_range_clamp() {
+        assert_eq!(255u8, saturating_map_range(5000u16, 0, 1023, 0, 255));
+        assert_eq!(0u8, saturating_map_range(-5i32, 0, 1023, 0, 255));
+        assert_eq!(10i8, saturating_map_range(-1000i64, 0, 100, 10, 20));
+        assert_eq!(20i8, saturating_map_range(u64::MAX, 0, 100, 10, 20));
+
+        // The output range is saturated into Dst
+        assert_eq!(u8::MAX, saturating_map_range(100i32, 0, 100, 0u8, 255));
+        assert_eq!(3u8, saturating_map_range(7i32, 7, 7, 3u8, 9));
+    }
+
+    #[test]
+    fn map_range_wide() {
+        // Products which overflow i128 fall back to f64
+        let (min, max) = (i64::MIN, i64::MAX);
+        assert_eq!(i64::MIN, saturating_map_range(min, min, max, min, max));
+        assert_eq!(i64::MAX, saturating_map_range(max, min, max, min, max));
+        assert_eq!(
+            0u128,
+            saturating_map_range(0u128, 0, u128::MAX, 0u128, u128::MAX)
+        );
+        assert_eq!(
+            i128::MAX,
+            saturating_map_range(i128::MAX, i128::MIN, i128::MAX, i128::MIN, i128::MAX)
+        );
+        let mid: i64 = saturating_map_range(0i64, min, max, min, max);
+        assert!(mid.unsigned_abs() < 1 << 12);
+    }
+}
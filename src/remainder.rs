@@ -0,0 +1,218 @@
+use core::convert::TryFrom;
+
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion between integer types, also returning the amount
+/// lost to saturation.
+///
+/// ```
+/// use saturate::SaturatingFromWithRemainder;
+///
+/// assert_eq!((255, 45), u8::saturating_from_with_remainder(300));
+/// assert_eq!((0, -20), u8::saturating_from_with_remainder(-20));
+/// assert_eq!((100, 0), u8::saturating_from_with_remainder(100));
+/// ```
+pub trait SaturatingFromWithRemainder<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, also returning the difference between `value` and
+    /// the converted result, in the units of `T`.
+    ///
+    /// The remainder is positive if `value` was greater than `Self::MAX`, negative if it was less
+    /// than `Self::MIN`, and zero if it was converted exactly. Adding the remainder to the result
+    /// (converted back to `T`) always gives the original value.
+    fn saturating_from_with_remainder(value: T) -> (Self, T);
+}
+
+macro_rules! impl_remainder {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromWithRemainder<$src> for $dst {
+                #[inline]
+                fn saturating_from_with_remainder(value: $src) -> ($dst, $src) {
+                    let result = <$dst>::saturating_from(value);
+                    // Converting back is exact, and the subtraction can't overflow since the
+                    // result is between `value` and zero whenever they differ
+                    (result, value - <$src>::saturating_from(result))
+                }
+            }
+        )+
+    };
+}
+
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u8);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u16);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u32);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u64);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u128);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => usize);
+
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i8);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i16);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i32);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i64);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i128);
+impl_remainder!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => isize);
+
+/// Trait to perform a saturating conversion between integer types, also returning the number of
+/// significant bits that didn't fit.
+///
+/// ```
+/// use saturate::SaturatingFromBitsLost;
+///
+/// assert_eq!((255, 12), u8::saturating_from_bits_lost(0xf_ffffu32));
+/// assert_eq!((-128, 1), i8::saturating_from_bits_lost(-129));
+/// assert_eq!((100, 0), u8::saturating_from_bits_lost(100));
+/// ```
+pub trait SaturatingFromBitsLost<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, also returning the number of significant bits of
+    /// `value` beyond those which `Self` can hold.
+    ///
+    /// The significant bits of a non-negative value are those up to and including its highest set
+    /// bit, e.g. 5 for `0b10110`. A negative value also needs a sign bit, so its significant bits
+    /// are those up to and including its highest clear bit, plus one, e.g. 8 for `-129`, which is
+    /// the minimum width of its two's complement representation. The number of bits lost is the
+    /// significant bits of `value` minus the significant bits of `Self::MAX` (or `Self::MIN` if
+    /// `value` is negative), or zero if that would be negative.
+    ///
+    /// The result is zero exactly when `value` is converted without saturating. Every negative
+    /// value saturates for an unsigned `Self` and loses all of its significant bits.
+    fn saturating_from_bits_lost(value: T) -> (Self, u32);
+}
+
+// Returns the significant bits of a value, as defined by saturating_from_bits_lost. Values which
+// don't fit in i128 are greater than i128::MAX, so all 128 bits are significant
+fn significant_bits<E>(value: Result<i128, E>) -> u32 {
+    match value {
+        Ok(value) if value < 0 => i128::BITS - (!value).leading_zeros() + 1,
+        Ok(value) => i128::BITS - value.leading_zeros(),
+        Err(_) => u128::BITS,
+    }
+}
+
+macro_rules! impl_bits_lost {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromBitsLost<$src> for $dst {
+                #[inline]
+                fn saturating_from_bits_lost(value: $src) -> ($dst, u32) {
+                    let wide = i128::try_from(value);
+                    let bound = match wide {
+                        Ok(wide) if wide < 0 => i128::try_from(<$dst>::MIN),
+                        _ => i128::try_from(<$dst>::MAX),
+                    };
+                    let lost = significant_bits(wide).saturating_sub(significant_bits(bound));
+                    (<$dst>::saturating_from(value), lost)
+                }
+            }
+        )+
+    };
+}
+
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u8);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u16);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u32);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u64);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u128);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => usize);
+
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i8);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i16);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i32);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i64);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i128);
+impl_bits_lost!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow() {
+        assert_eq!((255u8, 45i32), u8::saturating_from_with_remainder(300i32));
+        assert_eq!((127i8, 1u8), i8::saturating_from_with_remainder(128u8));
+        assert_eq!(
+            (i64::MAX, 1u64 << 63),
+            i64::saturating_from_with_remainder(u64::MAX)
+        );
+        let remainder = u128::MAX - u128::from(u32::MAX);
+        assert_eq!(
+            (u32::MAX, remainder),
+            u32::saturating_from_with_remainder(u128::MAX)
+        );
+        assert_eq!(
+            (i8::MAX, i128::MAX - 127),
+            i8::saturating_from_with_remainder(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn underflow() {
+        assert_eq!((0u8, -20i32), u8::saturating_from_with_remainder(-20i32));
+        assert_eq!(
+            (-128i8, -172i16),
+            i8::saturating_from_with_remainder(-300i16)
+        );
+        assert_eq!(
+            (0u128, i128::MIN),
+            u128::saturating_from_with_remainder(i128::MIN)
+        );
+        assert_eq!(
+            (i8::MIN, i128::MIN + 128),
+            i8::saturating_from_with_remainder(i128::MIN)
+        );
+        assert_eq!(
+            (0usize, -1isize),
+            usize::saturating_from_with_remainder(-1isize)
+        );
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!((100u8, 0i64), u8::saturating_from_with_remainder(100i64));
+        assert_eq!((-5i128, 0i8), i128::saturating_from_with_remainder(-5i8));
+        assert_eq!(
+            (u64::MAX, 0u64),
+            u64::saturating_from_with_remainder(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn bits_lost() {
+        assert_eq!((255u8, 12), u8::saturating_from_bits_lost(0xf_ffffu32));
+        assert_eq!((255u8, 1), u8::saturating_from_bits_lost(256u16));
+        assert_eq!((127i8, 1), i8::saturating_from_bits_lost(128u8));
+        assert_eq!((127i8, 1), i8::saturating_from_bits_lost(255i32));
+        assert_eq!((127i8, 2), i8::saturating_from_bits_lost(256i32));
+        assert_eq!((u32::MAX, 96), u32::saturating_from_bits_lost(u128::MAX));
+        assert_eq!((i64::MAX, 65), i64::saturating_from_bits_lost(u128::MAX));
+        assert_eq!((i8::MAX, 120), i8::saturating_from_bits_lost(i128::MAX));
+    }
+
+    #[test]
+    fn bits_lost_negative() {
+        assert_eq!((-128i8, 1), i8::saturating_from_bits_lost(-129i16));
+        assert_eq!((-128i8, 1), i8::saturating_from_bits_lost(-256i16));
+        assert_eq!((-128i8, 2), i8::saturating_from_bits_lost(-257i16));
+        assert_eq!((i8::MIN, 120), i8::saturating_from_bits_lost(i128::MIN));
+        assert_eq!((0u8, 1), u8::saturating_from_bits_lost(-1i8));
+        assert_eq!((0u8, 9), u8::saturating_from_bits_lost(-256i32));
+        assert_eq!((0u128, 128), u128::saturating_from_bits_lost(i128::MIN));
+    }
+
+    #[test]
+    fn bits_lost_exact() {
+        assert_eq!((0u8, 0), u8::saturating_from_bits_lost(0i64));
+        assert_eq!((255u8, 0), u8::saturating_from_bits_lost(255u64));
+        assert_eq!((-128i8, 0), i8::saturating_from_bits_lost(-128i32));
+        assert_eq!((-1i8, 0), i8::saturating_from_bits_lost(-1i128));
+        assert_eq!((u128::MAX, 0), u128::saturating_from_bits_lost(u128::MAX));
+        assert_eq!((i128::MIN, 0), i128::saturating_from_bits_lost(i128::MIN));
+
+        // Bits are lost exactly when the value saturates
+        for value in i16::MIN..=i16::MAX {
+            let (result, lost) = i8::saturating_from_bits_lost(value);
+            assert_eq!(i16::from(result) != value, lost > 0);
+            let (result, lost) = u8::saturating_from_bits_lost(value);
+            assert_eq!(i16::from(result) != value, lost > 0);
+        }
+    }
+}
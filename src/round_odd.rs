@@ -0,0 +1,98 @@
+use crate::SaturatingFrom;
+
+/// Converts `value` to `f32`, rounding to odd.
+///
+/// Inexact values are rounded toward zero, and the lowest bit of the result is then set. This
+/// means the result is never exactly halfway between two values of a narrower format, so it can be
+/// rounded again (e.g. to a 16 bit float) to the same result as rounding `value` directly, as long
+/// as the narrower format has at most 22 bits of precision. Rounding to nearest with `as` (and so
+/// [`SaturatingFrom`]) can instead round twice in the same direction, giving the wrong result.
+///
+/// Use this only when the result will be rounded again; otherwise rounding to nearest is more
+/// accurate. Values beyond the range of `f32` saturate to [`f32::MAX`] (or [`f32::MIN`]), as
+/// rounding toward zero never produces an infinity from a finite value. Infinities and NaN are
+/// converted as with [`SaturatingFrom`].
+///
+/// ```
+/// use saturate::saturating_from_round_to_odd;
+///
+/// // Halfway between two f32 values, which rounds to even with `as`
+/// let value = 1.0 + 2f64.powi(-24);
+/// assert_eq!(1.0, value as f32);
+/// assert_eq!(1.0 + 2f32.powi(-23), saturating_from_round_to_odd(value));
+/// ```
+#[inline]
+pub fn saturating_from_round_to_odd(value: f64) -> f32 {
+    let nearest = f32::saturating_from(value);
+    if value.is_nan() || value.is_infinite() || f64::from(nearest) == value {
+        return nearest;
+    }
+    // Stepping the bits of a float by one steps its magnitude to the adjacent representable
+    // value, including from infinity to the largest finite value and from zero to the smallest
+    // subnormal
+    let toward_zero = if f64::from(nearest).abs() > value.abs() {
+        f32::from_bits(nearest.to_bits() - 1)
+    } else {
+        nearest
+    };
+    f32::from_bits(toward_zero.to_bits() | 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_to_odd() {
+        // Exact values are unchanged
+        for &value in &[
+            0.0f64,
+            -0.0,
+            1.0,
+            -1.5,
+            f64::from(1e-40f32),
+            f64::from(f32::MAX),
+        ] {
+            let result = saturating_from_round_to_odd(value);
+            assert_eq!(value, f64::from(result));
+            assert_eq!(value.is_sign_negative(), result.is_sign_negative());
+        }
+
+        let ulp = 2f64.powi(-23);
+        let odd = 1.0 + 2f32.powi(-23);
+        assert_eq!(odd, saturating_from_round_to_odd(1.0 + ulp / 2.0));
+        assert_eq!(odd, saturating_from_round_to_odd(1.0 + ulp / 4.0));
+        assert_eq!(odd, saturating_from_round_to_odd(1.0 + ulp * 1.75));
+        assert_eq!(-odd, saturating_from_round_to_odd(-1.0 - ulp / 2.0));
+        assert_eq!(
+            1.0 + 3.0 * 2f32.powi(-23),
+            saturating_from_round_to_odd(1.0 + ulp * 2.5)
+        );
+
+        // Underflow and overflow
+        assert_eq!(
+            f32::from_bits(1),
+            saturating_from_round_to_odd(f64::from_bits(1))
+        );
+        assert_eq!(-f32::from_bits(1), saturating_from_round_to_odd(-1e-50));
+        assert_eq!(f32::MAX, saturating_from_round_to_odd(1e300));
+        assert_eq!(f32::MIN, saturating_from_round_to_odd(-f64::MAX));
+        assert_eq!(f32::INFINITY, saturating_from_round_to_odd(f64::INFINITY));
+        assert!(saturating_from_round_to_odd(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn double_rounding() {
+        // Rounding to 8 bits of precision via f32. The value is just above halfway between 1.0
+        // and 1.0 + 2^-7, so it should round up
+        let value = 1.0 + 2f64.powi(-8) + 2f64.powi(-40);
+        let round_bf16 = |value: f32| {
+            let bits = value.to_bits();
+            let rounded = bits + 0x7fff + ((bits >> 16) & 1);
+            f32::from_bits(rounded & 0xffff_0000)
+        };
+        let expected = 1.0 + 2f32.powi(-7);
+        assert_eq!(1.0, round_bf16(value as f32));
+        assert_eq!(expected, round_bf16(saturating_from_round_to_odd(value)));
+    }
+}
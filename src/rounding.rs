@@ -0,0 +1,424 @@
+use core::cmp::Ordering;
+
+use crate::classify::{Classify, Outcome};
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion from an integer to a floating point type, also
+/// reporting whether the value had to be rounded.
+///
+/// ```
+/// use saturate::SaturatingFromRoundNearestChecked;
+///
+/// assert_eq!((16777216.0, false), f32::saturating_from_round_nearest_checked(1u64 << 24));
+/// assert_eq!((16777216.0, true), f32::saturating_from_round_nearest_checked((1u64 << 24) + 1));
+/// ```
+pub trait SaturatingFromRoundNearestChecked<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, rounding to the nearest representable value. Also
+    /// returns `true` if the result is not exactly equal to `value`.
+    fn saturating_from_round_nearest_checked(value: T) -> (Self, bool);
+}
+
+macro_rules! impl_round_nearest_checked {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromRoundNearestChecked<$src> for $dst {
+                #[inline]
+                fn saturating_from_round_nearest_checked(value: $src) -> ($dst, bool) {
+                    let result = <$dst>::saturating_from(value);
+                    // The round trip can saturate (e.g. u64::MAX rounds up to 2^64 as f32, which
+                    // converts back to u64::MAX), so it must also be checked for overflow
+                    let (back, outcome) = <$src as Classify<$dst>>::classify(result);
+                    (result, outcome != Outcome::Exact || back != value)
+                }
+            }
+        )+
+    };
+}
+
+impl_round_nearest_checked!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f32);
+impl_round_nearest_checked!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f64);
+
+/// Trait to perform a saturating conversion from an integer to a floating point type with a choice
+/// of rounding direction.
+///
+/// Rounding toward and away from zero give the representable values either side of an inexact
+/// value, which is useful for computing conservative error bounds. Values beyond the largest
+/// finite float round to infinity unless rounded toward zero.
+///
+/// ```
+/// use saturate::SaturatingFromDirected;
+///
+/// let value = (1u32 << 24) + 1;
+/// assert_eq!(16777216.0, f32::saturating_from_nearest(value));
+/// assert_eq!(16777216.0, f32::saturating_from_toward_zero(value));
+/// assert_eq!(16777218.0, f32::saturating_from_away_from_zero(value));
+/// ```
+pub trait SaturatingFromDirected<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, rounding to the nearest representable value with
+    /// ties to even, i.e. the correctly rounded result. This is the same as
+    /// [`SaturatingFrom::saturating_from`].
+    fn saturating_from_nearest(value: T) -> Self;
+
+    /// Converts the input type `T` to `Self`, rounding toward zero
+    fn saturating_from_toward_zero(value: T) -> Self;
+
+    /// Converts the input type `T` to `Self`, rounding away from zero
+    fn saturating_from_away_from_zero(value: T) -> Self;
+}
+
+// Converts to the nearest float, also comparing the magnitude of the result to the magnitude of
+// the original value
+trait NearestMagnitude<T>: Sized {
+    fn nearest_magnitude(value: T) -> (Self, Ordering);
+}
+
+macro_rules! impl_directed {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl NearestMagnitude<$src> for $dst {
+                #[inline]
+                fn nearest_magnitude(value: $src) -> ($dst, Ordering) {
+                    let result = <$dst>::saturating_from(value);
+                    // Any inexact result is an integer, so it can be compared after converting
+                    // it back. If the round trip saturates then the result is beyond the range of
+                    // the source type, and so larger in magnitude than any value of it
+                    let ordering = match <$src as Classify<$dst>>::classify(result) {
+                        (_, Outcome::Overflow | Outcome::Underflow) => Ordering::Greater,
+                        (back, _) if result > 0.0 => back.cmp(&value),
+                        (back, _) => value.cmp(&back),
+                    };
+                    (result, ordering)
+                }
+            }
+
+            impl SaturatingFromDirected<$src> for $dst {
+                #[inline]
+                fn saturating_from_nearest(value: $src) -> $dst {
+                    <$dst>::saturating_from(value)
+                }
+
+                #[inline]
+                fn saturating_from_toward_zero(value: $src) -> $dst {
+                    // Stepping the bits of a nonzero float by one steps its magnitude to the
+                    // adjacent representable value. An inexact result is never zero
+                    match <$dst>::nearest_magnitude(value) {
+                        (result, Ordering::Greater) => <$dst>::from_bits(result.to_bits() - 1),
+                        (result, _) => result,
+                    }
+                }
+
+                #[inline]
+                fn saturating_from_away_from_zero(value: $src) -> $dst {
+                    match <$dst>::nearest_magnitude(value) {
+                        (result, Ordering::Less) => <$dst>::from_bits(result.to_bits() + 1),
+                        (result, _) => result,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_directed!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f32);
+impl_directed!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => f64);
+
+/// Trait to perform a saturating conversion from a floating point type to an integer, rounding to
+/// the nearest integer with ties rounded to even.
+///
+/// This is the default rounding mode of IEEE 754, and avoids the bias of rounding ties away from
+/// zero. NaN converts to zero and out of range values saturate, as with [`SaturatingFrom`].
+///
+/// ```
+/// use saturate::SaturatingFromTiesEven;
+///
+/// assert_eq!(0, i32::saturating_from_ties_even(0.5));
+/// assert_eq!(2, i32::saturating_from_ties_even(1.5));
+/// assert_eq!(2, i32::saturating_from_ties_even(2.5));
+/// ```
+pub trait SaturatingFromTiesEven<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, rounding ties to even
+    fn saturating_from_ties_even(value: T) -> Self;
+}
+
+macro_rules! impl_ties_even {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromTiesEven<$src> for $dst {
+                #[inline]
+                fn saturating_from_ties_even(value: $src) -> $dst {
+                    // round_ties_even requires Rust 1.77, so round ties away from zero and then
+                    // correct odd results. The subtraction is exact for any value with a
+                    // fractional part
+                    let rounded = value.round();
+                    let rounded = if (rounded - value).abs() == 0.5 && rounded % 2.0 != 0.0 {
+                        rounded - value.signum()
+                    } else {
+                        rounded
+                    };
+                    <$dst>::saturating_from(rounded)
+                }
+            }
+        )+
+    };
+}
+
+impl_ties_even!([f32, f64] => u8);
+impl_ties_even!([f32, f64] => u16);
+impl_ties_even!([f32, f64] => u32);
+impl_ties_even!([f32, f64] => u64);
+impl_ties_even!([f32, f64] => u128);
+impl_ties_even!([f32, f64] => usize);
+
+impl_ties_even!([f32, f64] => i8);
+impl_ties_even!([f32, f64] => i16);
+impl_ties_even!([f32, f64] => i32);
+impl_ties_even!([f32, f64] => i64);
+impl_ties_even!([f32, f64] => i128);
+impl_ties_even!([f32, f64] => isize);
+
+/// Trait to perform a saturating conversion from a floating point type to an integer, returning
+/// both the floor and the ceiling of the value.
+///
+/// This is useful for interval arithmetic, where the result must conservatively contain the
+/// original value. Each bound saturates independently, so an out of range value returns the same
+/// bound twice, and NaN returns zero for both.
+///
+/// ```
+/// use saturate::SaturatingFromBounds;
+///
+/// assert_eq!((2, 3), i32::saturating_from_bounds(2.3));
+/// assert_eq!((-3, -2), i32::saturating_from_bounds(-2.3));
+/// assert_eq!((255, 255), u8::saturating_from_bounds(300.5));
+/// ```
+pub trait SaturatingFromBounds<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, returning `(floor, ceil)`
+    fn saturating_from_bounds(value: T) -> (Self, Self);
+}
+
+macro_rules! impl_bounds {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingFromBounds<$src> for $dst {
+                #[inline]
+                fn saturating_from_bounds(value: $src) -> ($dst, $dst) {
+                    (<$dst>::saturating_from(value.floor()), <$dst>::saturating_from(value.ceil()))
+                }
+            }
+        )+
+    };
+}
+
+impl_bounds!([f32, f64] => u8);
+impl_bounds!([f32, f64] => u16);
+impl_bounds!([f32, f64] => u32);
+impl_bounds!([f32, f64] => u64);
+impl_bounds!([f32, f64] => u128);
+impl_bounds!([f32, f64] => usize);
+
+impl_bounds!([f32, f64] => i8);
+impl_bounds!([f32, f64] => i16);
+impl_bounds!([f32, f64] => i32);
+impl_bounds!([f32, f64] => i64);
+impl_bounds!([f32, f64] => i128);
+impl_bounds!([f32, f64] => isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_nearest_checked() {
+        let value = (1u64 << 40) + 1;
+        assert_eq!(
+            (value as f32, true),
+            f32::saturating_from_round_nearest_checked(value)
+        );
+        assert_eq!(
+            (value as f64, false),
+            f64::saturating_from_round_nearest_checked(value)
+        );
+        let value = 1u64 << 40;
+        assert_eq!(
+            (value as f32, false),
+            f32::saturating_from_round_nearest_checked(value)
+        );
+
+        assert_eq!(
+            (-128.0, false),
+            f32::saturating_from_round_nearest_checked(i8::MIN)
+        );
+        assert_eq!(
+            (65535.0, false),
+            f32::saturating_from_round_nearest_checked(u16::MAX)
+        );
+        assert_eq!(
+            (16777215.0, false),
+            f32::saturating_from_round_nearest_checked(16777215i32)
+        );
+        assert_eq!(
+            (16777216.0, true),
+            f32::saturating_from_round_nearest_checked(16777217i32)
+        );
+        assert_eq!(
+            (-4294967296.0, false),
+            f32::saturating_from_round_nearest_checked(-1i64 << 32)
+        );
+
+        // Values which round to a bound of the source type
+        let (result, lossy) = f32::saturating_from_round_nearest_checked(u64::MAX);
+        assert_eq!((18446744073709551616.0, true), (result, lossy));
+        let (result, lossy) = f64::saturating_from_round_nearest_checked(i64::MAX);
+        assert_eq!((9223372036854775808.0, true), (result, lossy));
+        assert_eq!(
+            (i64::MIN as f64, false),
+            f64::saturating_from_round_nearest_checked(i64::MIN)
+        );
+        assert_eq!(
+            (f32::INFINITY, true),
+            f32::saturating_from_round_nearest_checked(u128::MAX)
+        );
+        assert_eq!(
+            (i128::MIN as f32, false),
+            f32::saturating_from_round_nearest_checked(i128::MIN)
+        );
+        let (_, lossy) = f64::saturating_from_round_nearest_checked(usize::MAX);
+        assert_eq!(usize::BITS > 53, lossy);
+    }
+
+    #[test]
+    fn directed() {
+        // Exact values are unchanged
+        for &value in &[0i64, 1, -1, 1 << 24, -(1 << 40), i64::MIN] {
+            assert_eq!(value as f32, f32::saturating_from_toward_zero(value));
+            assert_eq!(value as f32, f32::saturating_from_away_from_zero(value));
+        }
+
+        // Rounded down to the nearest value
+        let value = (1u64 << 24) + 1;
+        assert_eq!(16777216.0f32, f32::saturating_from_nearest(value));
+        assert_eq!(16777216.0f32, f32::saturating_from_toward_zero(value));
+        assert_eq!(16777218.0f32, f32::saturating_from_away_from_zero(value));
+        assert_eq!(16777217.0f64, f64::saturating_from_toward_zero(value));
+        assert_eq!(16777217.0f64, f64::saturating_from_away_from_zero(value));
+
+        // Rounded up to the nearest value
+        let value = (1i64 << 24) + 3;
+        assert_eq!(16777220.0f32, f32::saturating_from_nearest(value));
+        assert_eq!(16777218.0f32, f32::saturating_from_toward_zero(value));
+        assert_eq!(16777220.0f32, f32::saturating_from_away_from_zero(value));
+
+        // Negative values round symmetrically
+        assert_eq!(-16777218.0f32, f32::saturating_from_toward_zero(-value));
+        assert_eq!(-16777220.0f32, f32::saturating_from_away_from_zero(-value));
+        let value = -(1i64 << 24) - 1;
+        assert_eq!(-16777216.0f32, f32::saturating_from_toward_zero(value));
+        assert_eq!(-16777218.0f32, f32::saturating_from_away_from_zero(value));
+
+        // The nearest value is beyond the range of the source type
+        assert_eq!(
+            18446742974197923840.0f32,
+            f32::saturating_from_toward_zero(u64::MAX)
+        );
+        assert_eq!(
+            18446744073709551616.0f32,
+            f32::saturating_from_away_from_zero(u64::MAX)
+        );
+        assert_eq!(
+            9223372036854774784.0f64,
+            f64::saturating_from_toward_zero(i64::MAX)
+        );
+        assert_eq!(f32::MAX, f32::saturating_from_toward_zero(u128::MAX));
+        assert_eq!(
+            f32::INFINITY,
+            f32::saturating_from_away_from_zero(u128::MAX)
+        );
+        assert_eq!(
+            -170141183460469231731687303715884105728.0f32,
+            f32::saturating_from_toward_zero(i128::MIN)
+        );
+        assert_eq!(
+            -170141173319264429905852091742258462720.0f32,
+            f32::saturating_from_toward_zero(i128::MIN + 1)
+        );
+
+        // The bounds always contain the value
+        let mut value = 0x0123_4567_89ab_cdefu64;
+        for _ in 0..1000 {
+            value = value.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let lower = f32::saturating_from_toward_zero(value);
+            let upper = f32::saturating_from_away_from_zero(value);
+            assert!(u128::from(lower.to_bits()) + 1 >= u128::from(upper.to_bits()));
+            assert!(u64::saturating_from(lower) <= value);
+            assert!(u128::saturating_from(upper) >= u128::from(value));
+        }
+    }
+
+    #[test]
+    fn ties_even() {
+        assert_eq!(0i32, i32::saturating_from_ties_even(0.5f64));
+        assert_eq!(2i32, i32::saturating_from_ties_even(1.5f64));
+        assert_eq!(2i32, i32::saturating_from_ties_even(2.5f64));
+        assert_eq!(4i32, i32::saturating_from_ties_even(3.5f32));
+        assert_eq!(-2i8, i8::saturating_from_ties_even(-2.5f32));
+        assert_eq!(-4i8, i8::saturating_from_ties_even(-3.5f64));
+        assert_eq!(0i8, i8::saturating_from_ties_even(-0.5f64));
+        assert_eq!(3u8, u8::saturating_from_ties_even(2.6f32));
+        assert_eq!(2u8, u8::saturating_from_ties_even(2.4f32));
+        assert_eq!(0u8, u8::saturating_from_ties_even(0.49999999999999994f64));
+        assert_eq!(
+            9007199254740992i64,
+            i64::saturating_from_ties_even(9007199254740992.0f64)
+        );
+        assert_eq!(
+            4503599627370497i64,
+            i64::saturating_from_ties_even(4503599627370497.0f64)
+        );
+    }
+
+    #[test]
+    fn ties_even_saturate() {
+        assert_eq!(0u16, u16::saturating_from_ties_even(f32::NAN));
+        assert_eq!(0i64, i64::saturating_from_ties_even(-f64::NAN));
+        assert_eq!(255u8, u8::saturating_from_ties_even(255.5f64));
+        assert_eq!(254u8, u8::saturating_from_ties_even(254.5f64));
+        assert_eq!(0u8, u8::saturating_from_ties_even(-1.5f64));
+        assert_eq!(i32::MAX, i32::saturating_from_ties_even(f64::INFINITY));
+        assert_eq!(i32::MIN, i32::saturating_from_ties_even(f32::NEG_INFINITY));
+        assert_eq!(u128::MAX, u128::saturating_from_ties_even(f64::MAX));
+    }
+
+    #[test]
+    fn bounds() {
+        // Fractional values
+        assert_eq!((2i32, 3i32), i32::saturating_from_bounds(2.3f64));
+        assert_eq!((0u8, 1u8), u8::saturating_from_bounds(0.5f32));
+
+        // Exact integers
+        assert_eq!((7i32, 7i32), i32::saturating_from_bounds(7.0f64));
+        assert_eq!((0u16, 0u16), u16::saturating_from_bounds(-0.0f32));
+
+        // Negative values
+        assert_eq!((-3i32, -2i32), i32::saturating_from_bounds(-2.3f64));
+        assert_eq!((-1i8, 0i8), i8::saturating_from_bounds(-0.5f32));
+        assert_eq!((0u8, 0u8), u8::saturating_from_bounds(-0.5f64));
+
+        // Out of range values
+        assert_eq!((u8::MAX, u8::MAX), u8::saturating_from_bounds(300.5f64));
+        assert_eq!((i8::MIN, i8::MIN), i8::saturating_from_bounds(-1e10f32));
+        assert_eq!(
+            (u32::MAX, u32::MAX),
+            u32::saturating_from_bounds(f64::INFINITY)
+        );
+        assert_eq!(
+            (i64::MIN, i64::MIN),
+            i64::saturating_from_bounds(f64::NEG_INFINITY)
+        );
+
+        // Values straddling a bound of the target type
+        assert_eq!((u8::MAX, u8::MAX), u8::saturating_from_bounds(255.5f32));
+        assert_eq!((i8::MIN, -127i8), i8::saturating_from_bounds(-127.5f64));
+
+        assert_eq!((0i32, 0i32), i32::saturating_from_bounds(f64::NAN));
+        assert_eq!((0u64, 0u64), u64::saturating_from_bounds(f32::NAN));
+    }
+}
@@ -0,0 +1,193 @@
+// The num-saturating feature documents its own higher minimum Rust version
+#![allow(clippy::incompatible_msrv)]
+
+use core::num::{Saturating, Wrapping};
+
+use crate::SaturatingFrom;
+
+/// Integer types, the only types for which [`Saturating`] arithmetic is defined.
+///
+/// Although `Saturating<f32>` can be constructed it never saturates, so converting it is almost
+/// certainly a mistake and is rejected at compile time:
+///
+/// ```compile_fail
+/// use core::num::Saturating;
+/// use saturate::SaturatingFrom;
+///
+/// let value = u8::saturating_from(Saturating(1.5f32));
+/// ```
+pub trait SaturatingInteger {}
+
+macro_rules! impl_saturating_integer {
+    ($($typ:ty),+) => {
+        $(
+            impl SaturatingInteger for $typ {}
+        )+
+    };
+}
+
+impl_saturating_integer!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_saturating {
+    ($($typ:ty),+) => {
+        $(
+            /// Converts the inner value. Only integer values are accepted
+            impl<T> SaturatingFrom<Saturating<T>> for $typ
+            where
+                T: SaturatingInteger,
+                $typ: SaturatingFrom<T>,
+            {
+                const IS_WIDENING: bool = <$typ as SaturatingFrom<T>>::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: Saturating<T>) -> $typ {
+                    <$typ>::saturating_from(value.0)
+                }
+            }
+
+            /// Converts the value and wraps the result, so that further arithmetic also saturates
+            impl<T> SaturatingFrom<T> for Saturating<$typ>
+            where
+                $typ: SaturatingFrom<T>,
+            {
+                const IS_WIDENING: bool = <$typ as SaturatingFrom<T>>::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: T) -> Saturating<$typ> {
+                    Saturating(<$typ>::saturating_from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_saturating!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_wrapping_from_saturating {
+    ($($typ:ty),+) => {
+        $(
+            /// Converts the inner value and wraps the result, so that further arithmetic wraps.
+            /// The conversion itself still clamps. The reverse conversion, from [`Wrapping`] to
+            /// [`Saturating`], is covered by the implementation above
+            impl<T> SaturatingFrom<Saturating<T>> for Wrapping<$typ>
+            where
+                T: SaturatingInteger,
+                $typ: SaturatingFrom<T>,
+            {
+                const IS_WIDENING: bool = <$typ as SaturatingFrom<T>>::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: Saturating<T>) -> Wrapping<$typ> {
+                    Wrapping(<$typ>::saturating_from(value.0))
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapping_from_saturating!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingInto;
+
+    #[test]
+    fn impl_from_saturating() {
+        assert_eq!(0u8, u8::saturating_from(Saturating(-1i64)));
+        assert_eq!(i16::MAX, Saturating(1e10f64).0.saturating_into());
+        assert_eq!(i16::MAX, i16::saturating_from(Saturating(u32::MAX)));
+        assert_eq!(42i128, i128::saturating_from(Saturating(42u8)));
+    }
+
+    #[test]
+    fn impl_to_saturating() {
+        assert_eq!(Saturating(255u8), Saturating::saturating_from(300i32));
+        assert_eq!(Saturating(0u16), Saturating::saturating_from(f32::NAN));
+        assert_eq!(
+            Saturating(-128i8),
+            Saturating::saturating_from(Saturating(-1000i64))
+        );
+        let value: Saturating<u32> = (-5i8).saturating_into();
+        assert_eq!(Saturating(0), value);
+    }
+
+    #[test]
+    fn has_impl() {
+        fn has_impl_inner<T: SaturatingFrom<U>, U: SaturatingInto<T>>() {}
+
+        macro_rules! check_impls {
+            (@inner [$src:ty], [$($dst:ty),+]) => {$(
+                has_impl_inner::<$dst, Saturating<$src>>();
+                has_impl_inner::<Saturating<$dst>, $src>();
+                has_impl_inner::<Saturating<$dst>, Saturating<$src>>();
+                has_impl_inner::<Saturating<$dst>, Wrapping<$src>>();
+                has_impl_inner::<Wrapping<$dst>, Saturating<$src>>();
+            )*};
+            (@inner [$($src:ty),+], $dst:tt) => {$(
+                check_impls!(@inner [$src], $dst);
+            )*};
+            ($($typ:ty),+) => {
+                check_impls!(@inner [$($typ),+], [$($typ),+]);
+            };
+        }
+
+        // Will fail to compile if any permutation is not implemented
+        check_impls!(usize, isize, u128, i128, u64, i64, u32, i32, u16, i16, u8, i8);
+    }
+
+    #[test]
+    fn is_widening() {
+        fn is_widening<T: SaturatingFrom<U>, U>() -> bool {
+            T::IS_WIDENING
+        }
+
+        assert!(is_widening::<u32, Saturating<u16>>());
+        assert!(is_widening::<Saturating<i64>, i32>());
+        assert!(is_widening::<Saturating<i128>, Saturating<u64>>());
+        assert!(!is_widening::<u8, Saturating<i8>>());
+        assert!(!is_widening::<Saturating<u16>, u32>());
+        assert!(!is_widening::<Saturating<i8>, Saturating<u64>>());
+    }
+
+    #[test]
+    fn saturating_to_saturating() {
+        assert_eq!(
+            Saturating(u8::MAX),
+            Saturating::saturating_from(Saturating(300i32))
+        );
+        assert_eq!(
+            Saturating(0u64),
+            Saturating::saturating_from(Saturating(i8::MIN))
+        );
+        assert_eq!(
+            Saturating(i16::MIN),
+            Saturating::saturating_from(Saturating(i128::MIN))
+        );
+        assert_eq!(
+            Saturating(42usize),
+            Saturating::saturating_from(Saturating(42u8))
+        );
+    }
+
+    #[test]
+    fn wrapping_interop() {
+        assert_eq!(
+            Saturating(255u8),
+            Saturating::saturating_from(Wrapping(300i32))
+        );
+        assert_eq!(
+            Saturating(0u16),
+            Saturating::saturating_from(Wrapping(i64::MIN))
+        );
+        assert_eq!(
+            Wrapping(i8::MIN),
+            Wrapping::saturating_from(Saturating(-1000i32))
+        );
+        assert_eq!(Wrapping(42u64), Wrapping::saturating_from(Saturating(42u8)));
+
+        // The converted value wraps in further arithmetic
+        let value: Wrapping<u8> = Saturating(u32::MAX).saturating_into();
+        assert_eq!(Wrapping(0u8), value + Wrapping(1));
+    }
+}
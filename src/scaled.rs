@@ -0,0 +1,68 @@
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion to an integer type after multiplying by a scale
+/// factor, such as when converting between units.
+///
+/// The value is converted to [`f64`], multiplied by `scale`, and rounded to the nearest integer
+/// (with ties rounded away from zero) before saturating. Integers too large to be represented
+/// exactly as [`f64`] are rounded before scaling. NaN results, such as from `0 * inf`, convert to
+/// zero.
+///
+/// ```
+/// use saturate::SaturatingFromScaled;
+///
+/// // Millivolts to volts
+/// assert_eq!(5, i32::saturating_from_scaled(5000, 0.001));
+/// assert_eq!(255, u8::saturating_from_scaled(1000, 1e3));
+/// ```
+pub trait SaturatingFromScaled<T>: SaturatingFrom<T> {
+    /// Converts `value * scale` to `Self`, rounding to the nearest integer
+    fn saturating_from_scaled(value: T, scale: f64) -> Self;
+}
+
+macro_rules! impl_scaled {
+    ($($dst:ty),+) => {
+        $(
+            impl<T> SaturatingFromScaled<T> for $dst
+            where
+                f64: SaturatingFrom<T>,
+                $dst: SaturatingFrom<T>,
+            {
+                #[inline]
+                fn saturating_from_scaled(value: T, scale: f64) -> $dst {
+                    let scaled = f64::saturating_from(value) * scale;
+                    <$dst as SaturatingFrom<f64>>::saturating_from(scaled.round())
+                }
+            }
+        )+
+    };
+}
+
+impl_scaled!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled() {
+        assert_eq!(5i32, i32::saturating_from_scaled(5000i32, 0.001));
+        assert_eq!(5i32, i32::saturating_from_scaled(4500u16, 0.001));
+        assert_eq!(4i32, i32::saturating_from_scaled(4499i64, 0.001));
+        assert_eq!(-5i32, i32::saturating_from_scaled(-4500i64, 0.001));
+        assert_eq!(-12i8, i8::saturating_from_scaled(3i8, -4.0));
+        assert_eq!(3u16, u16::saturating_from_scaled(1.5f32, 2.0));
+        assert_eq!(1000u64, u64::saturating_from_scaled(true, 1e3));
+    }
+
+    #[test]
+    fn scaled_saturate() {
+        assert_eq!(u8::MAX, u8::saturating_from_scaled(1000i32, 1e3));
+        assert_eq!(u8::MIN, u8::saturating_from_scaled(1000i32, -1e3));
+        assert_eq!(i32::MAX, i32::saturating_from_scaled(1u8, f64::INFINITY));
+        assert_eq!(i64::MIN, i64::saturating_from_scaled(i64::MIN, 2.0));
+        assert_eq!(0i32, i32::saturating_from_scaled(0i32, f64::INFINITY));
+        assert_eq!(0i32, i32::saturating_from_scaled(f64::INFINITY, 0.0));
+        assert_eq!(0u32, u32::saturating_from_scaled(5u32, f64::NAN));
+    }
+}
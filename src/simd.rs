@@ -0,0 +1,111 @@
+use core::simd::cmp::SimdOrd;
+use core::simd::num::{SimdFloat, SimdInt};
+use core::simd::{Simd, SimdElement};
+
+use crate::{saturating_convert_slice, SaturatingFrom};
+
+const LANES: usize = 16;
+
+/// Trait for conversions with a SIMD implementation, used by
+/// [`saturating_convert_slice_simd`].
+///
+/// Results must be identical to those of [`SaturatingFrom`].
+pub trait SimdSaturatingFrom<T: SimdElement>: SaturatingFrom<T> + SimdElement {
+    /// Converts each lane of `value` to `Self`
+    fn saturating_from_simd(value: Simd<T, LANES>) -> Simd<Self, LANES>;
+}
+
+impl SimdSaturatingFrom<i32> for u8 {
+    #[inline]
+    fn saturating_from_simd(value: Simd<i32, LANES>) -> Simd<u8, LANES> {
+        let (min, max) = (Simd::splat(0), Simd::splat(i32::from(u8::MAX)));
+        value.simd_clamp(min, max).cast()
+    }
+}
+
+impl SimdSaturatingFrom<f32> for u8 {
+    #[inline]
+    fn saturating_from_simd(value: Simd<f32, LANES>) -> Simd<u8, LANES> {
+        // simd_max returns the non-NaN operand, so NaN becomes 0 as it does with `as`
+        let (min, max) = (Simd::splat(0.0), Simd::splat(f32::from(u8::MAX)));
+        let clamped = value.simd_max(min).simd_min(max);
+        // SAFETY: `clamped` is never NaN and lies within `0.0..=255.0`, so it fits in an i32.
+        // A saturating float cast is much slower than the plain truncating conversion
+        unsafe { clamped.to_int_unchecked::<i32>() }.cast()
+    }
+}
+
+/// Converts each element of `src` into the corresponding element of `dst` using SIMD.
+///
+/// This produces identical results to [`saturating_convert_slice`], processing elements in
+/// batches with a scalar fallback for any remainder.
+///
+/// Requires a nightly compiler and the `portable-simd` feature.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn saturating_convert_slice_simd<Src, Dst>(src: &[Src], dst: &mut [Dst])
+where
+    Src: SimdElement,
+    Dst: SimdSaturatingFrom<Src>,
+{
+    assert_eq!(src.len(), dst.len(), "slices have different lengths");
+
+    let (src_chunks, dst_chunks) = (src.chunks_exact(LANES), dst.chunks_exact_mut(LANES));
+    let src_tail = src_chunks.remainder();
+    for (dst, src) in dst_chunks.zip(src_chunks) {
+        Dst::saturating_from_simd(Simd::from_slice(src)).copy_to_slice(dst);
+    }
+
+    let tail = src.len() - src_tail.len();
+    saturating_convert_slice(src_tail, &mut dst[tail..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Simple xorshift generator so the differential tests are reproducible
+    fn random_bits(state: &mut u32) -> u32 {
+        *state ^= *state << 13;
+        *state ^= *state >> 17;
+        *state ^= *state << 5;
+        *state
+    }
+
+    #[test]
+    fn simd_matches_scalar_i32() {
+        let mut state = 0x2545_f491;
+        let mut src: Vec<_> = (0..1027)
+            .map(|_| random_bits(&mut state) as i32 >> 20)
+            .collect();
+        src.extend_from_slice(&[i32::MIN, -1, 0, 255, 256, i32::MAX]);
+
+        let (mut scalar, mut simd) = (vec![0u8; src.len()], vec![0u8; src.len()]);
+        saturating_convert_slice(&src, &mut scalar);
+        saturating_convert_slice_simd(&src, &mut simd);
+        assert_eq!(scalar, simd);
+    }
+
+    #[test]
+    fn simd_matches_scalar_f32() {
+        let mut state = 0x9e37_79b9;
+        let mut src: Vec<_> = (0..1027)
+            .map(|_| f32::from_bits(random_bits(&mut state)))
+            .collect();
+        src.extend_from_slice(&[f32::NAN, -f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+        src.extend_from_slice(&[-0.0, 0.5, 254.9, 255.0, 255.5, 256.0, -1.0]);
+
+        let (mut scalar, mut simd) = (vec![0u8; src.len()], vec![0u8; src.len()]);
+        saturating_convert_slice(&src, &mut scalar);
+        saturating_convert_slice_simd(&src, &mut simd);
+        assert_eq!(scalar, simd);
+    }
+
+    #[test]
+    #[should_panic]
+    fn simd_len_mismatch() {
+        saturating_convert_slice_simd(&[0i32; 3], &mut [0u8; 4]);
+    }
+}
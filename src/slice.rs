@@ -0,0 +1,119 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::mem::{self, ManuallyDrop};
+
+use crate::SaturatingFrom;
+
+/// Converts each element of `src` into the corresponding element of `dst`.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// ```
+/// use saturate::saturating_convert_slice;
+///
+/// let mut dst = [0u8; 4];
+/// saturating_convert_slice(&[-26i32, 76, 935, 255], &mut dst);
+/// assert_eq!([0, 76, 255, 255], dst);
+/// ```
+pub fn saturating_convert_slice<Src, Dst>(src: &[Src], dst: &mut [Dst])
+where
+    Src: Copy,
+    Dst: SaturatingFrom<Src>,
+{
+    assert_eq!(src.len(), dst.len(), "slices have different lengths");
+    for (dst, &src) in dst.iter_mut().zip(src) {
+        *dst = Dst::saturating_from(src);
+    }
+}
+
+/// Converts each element of `src`, returning `src` unchanged without allocating if `Src` and `Dst`
+/// are the same type.
+///
+/// Otherwise the elements are converted into a newly allocated [`Vec`], even if `src` is already
+/// owned.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use saturate::saturating_cow;
+///
+/// let src = [-26i32, 76, 935];
+/// let same: Cow<[i32]> = saturating_cow(Cow::Borrowed(&src[..]));
+/// assert!(matches!(same, Cow::Borrowed(_)));
+///
+/// let converted: Cow<[u8]> = saturating_cow(Cow::Borrowed(&src[..]));
+/// assert_eq!([0, 76, 255], *converted);
+/// ```
+pub fn saturating_cow<'a, Dst, Src>(src: Cow<'a, [Src]>) -> Cow<'a, [Dst]>
+where
+    Src: Copy + 'static,
+    Dst: SaturatingFrom<Src> + Clone + 'static,
+{
+    if TypeId::of::<Src>() == TypeId::of::<Dst>() {
+        let src = ManuallyDrop::new(src);
+        // SAFETY: Src and Dst are the same type, so this is a move of src into an identical type
+        return unsafe { mem::transmute_copy::<Cow<'a, [Src]>, Cow<'a, [Dst]>>(&src) };
+    }
+    Cow::Owned(
+        src.iter()
+            .map(|&value| Dst::saturating_from(value))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_slice() {
+        let mut dst = [0u16; 5];
+        saturating_convert_slice(&[-1.0f32, 1.5, 7e5, f32::NAN, 65535.0], &mut dst);
+        assert_eq!([0, 1, 65535, 0, 65535], dst);
+
+        let mut dst: [i8; 0] = [];
+        saturating_convert_slice(&[0u64; 0], &mut dst);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_slice_len_mismatch() {
+        saturating_convert_slice(&[0i32; 3], &mut [0u8; 4]);
+    }
+
+    #[test]
+    fn cow_same_type() {
+        let src = [1.5f32, f32::NAN, -0.0];
+        let result: Cow<[f32]> = saturating_cow(Cow::Borrowed(&src[..]));
+        match result {
+            Cow::Borrowed(slice) => assert!(core::ptr::eq(slice, &src[..])),
+            Cow::Owned(_) => panic!("same type conversion allocated"),
+        }
+
+        let src = vec![-1i64, 0, i64::MAX];
+        let ptr = src.as_ptr();
+        let result: Cow<[i64]> = saturating_cow(Cow::Owned(src));
+        match result {
+            Cow::Owned(vec) => {
+                assert_eq!(ptr, vec.as_ptr());
+                assert_eq!([-1, 0, i64::MAX], *vec);
+            }
+            Cow::Borrowed(_) => panic!("owned input was borrowed"),
+        }
+    }
+
+    #[test]
+    fn cow_convert() {
+        let src = [-1i64, 300, 7];
+        let result: Cow<[u8]> = saturating_cow(Cow::Borrowed(&src[..]));
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!([0, 255, 7], *result);
+
+        let result: Cow<[i16]> = saturating_cow(Cow::Owned(vec![f64::NAN, 1e9, -1e9, 2.5]));
+        assert_eq!([0, i16::MAX, i16::MIN, 2], *result);
+
+        let result: Cow<[u32]> = saturating_cow::<u32, u8>(Cow::Borrowed(&[]));
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::SaturatingFrom;
+
+/// Trait for types which declare the numeric type they produce, such as the stages of a
+/// processing pipeline.
+///
+/// Combined with [`saturate_assoc`], this allows generic code to convert a value to the output
+/// type of a stage without naming that type.
+pub trait HasNumericOutput {
+    /// The numeric type produced
+    type Output;
+}
+
+/// Converts `value` to the [`Output`](HasNumericOutput::Output) type of `S`.
+///
+/// ```
+/// use saturate::{saturate_assoc, HasNumericOutput};
+///
+/// struct Brightness;
+///
+/// impl HasNumericOutput for Brightness {
+///     type Output = u8;
+/// }
+///
+/// assert_eq!(255, saturate_assoc::<Brightness, _>(1000i64));
+/// ```
+#[inline]
+pub fn saturate_assoc<S, T>(value: T) -> S::Output
+where
+    S: HasNumericOutput,
+    S::Output: SaturatingFrom<T>,
+{
+    S::Output::saturating_from(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Narrow;
+
+    impl HasNumericOutput for Narrow {
+        type Output = u8;
+    }
+
+    struct Signed;
+
+    impl HasNumericOutput for Signed {
+        type Output = i16;
+    }
+
+    fn run<S: HasNumericOutput>(input: i64) -> S::Output
+    where
+        S::Output: SaturatingFrom<i64>,
+    {
+        saturate_assoc::<S, _>(input)
+    }
+
+    #[test]
+    fn assoc() {
+        assert_eq!(0u8, run::<Narrow>(-1));
+        assert_eq!(200u8, run::<Narrow>(200));
+        assert_eq!(255u8, run::<Narrow>(i64::MAX));
+        assert_eq!(i16::MIN, run::<Signed>(i64::MIN));
+        assert_eq!(-5i16, saturate_assoc::<Signed, _>(-5.4f64));
+    }
+}
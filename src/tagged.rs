@@ -0,0 +1,118 @@
+use core::cmp::Ordering;
+
+use crate::classify::{Classify, Outcome};
+use crate::SaturatingFrom;
+
+/// Trait to perform a saturating conversion to an integer type, also reporting which side of
+/// the target's range the value was on.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use saturate::SaturatingFromTagged;
+///
+/// assert_eq!((0, Ordering::Less), u8::saturating_from_tagged(-5));
+/// assert_eq!((255, Ordering::Greater), u8::saturating_from_tagged(300));
+/// assert_eq!((42, Ordering::Equal), u8::saturating_from_tagged(42.5));
+/// ```
+pub trait SaturatingFromTagged<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, also returning [`Ordering::Less`] if `value` was
+    /// clamped to `Self::MIN`, [`Ordering::Greater`] if it was clamped to `Self::MAX`, or
+    /// [`Ordering::Equal`] if it was within range (but possibly rounded).
+    ///
+    /// NaN is neither above nor below the range, so it is reported as [`Ordering::Equal`]. Use
+    /// [`SaturatingFromFinite`](crate::SaturatingFromFinite) if NaN needs to be detected.
+    fn saturating_from_tagged(value: T) -> (Self, Ordering);
+}
+
+impl<T, U: Classify<T>> SaturatingFromTagged<T> for U {
+    #[inline]
+    fn saturating_from_tagged(value: T) -> (Self, Ordering) {
+        match U::classify(value) {
+            (result, Outcome::Underflow) => (result, Ordering::Less),
+            (result, Outcome::Overflow) => (result, Ordering::Greater),
+            (result, Outcome::Exact | Outcome::Rounded | Outcome::Nan) => (result, Ordering::Equal),
+        }
+    }
+}
+
+/// Trait to perform a saturating conversion to an integer type, also reporting which side of
+/// the target's range the value was on as a compact `i8`.
+///
+/// ```
+/// use saturate::SaturatingFromDir;
+///
+/// assert_eq!((0, -1), u8::saturating_from_dir(-5));
+/// assert_eq!((255, 1), u8::saturating_from_dir(300));
+/// assert_eq!((42, 0), u8::saturating_from_dir(42.5));
+/// ```
+pub trait SaturatingFromDir<T>: SaturatingFromTagged<T> {
+    /// Converts the input type `T` to `Self`, also returning `-1` if `value` was clamped to
+    /// `Self::MIN`, `1` if it was clamped to `Self::MAX`, or `0` if it was within range (but
+    /// possibly rounded). This is the same as
+    /// [`saturating_from_tagged`](SaturatingFromTagged::saturating_from_tagged) with the
+    /// [`Ordering`] cast to an `i8`.
+    fn saturating_from_dir(value: T) -> (Self, i8);
+}
+
+impl<T, U: SaturatingFromTagged<T>> SaturatingFromDir<T> for U {
+    #[inline]
+    fn saturating_from_dir(value: T) -> (Self, i8) {
+        let (result, ordering) = U::saturating_from_tagged(value);
+        (result, ordering as i8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_source() {
+        assert_eq!((0u8, Ordering::Less), u8::saturating_from_tagged(-5i32));
+        assert_eq!(
+            (255u8, Ordering::Greater),
+            u8::saturating_from_tagged(300i32)
+        );
+        assert_eq!((200u8, Ordering::Equal), u8::saturating_from_tagged(200i32));
+        assert_eq!(
+            (i64::MIN, Ordering::Less),
+            i64::saturating_from_tagged(i128::MIN)
+        );
+        assert_eq!(
+            (u128::MAX, Ordering::Equal),
+            u128::saturating_from_tagged(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn float_source() {
+        assert_eq!((0u8, Ordering::Less), u8::saturating_from_tagged(-1.0f32));
+        assert_eq!((0u8, Ordering::Equal), u8::saturating_from_tagged(-0.5f32));
+        assert_eq!(
+            (255u8, Ordering::Equal),
+            u8::saturating_from_tagged(255.5f64)
+        );
+        assert_eq!(
+            (255u8, Ordering::Greater),
+            u8::saturating_from_tagged(256.0f64)
+        );
+        assert_eq!(
+            (i32::MIN, Ordering::Less),
+            i32::saturating_from_tagged(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            (0i32, Ordering::Equal),
+            i32::saturating_from_tagged(f32::NAN)
+        );
+    }
+
+    #[test]
+    fn dir() {
+        assert_eq!((0u8, -1), u8::saturating_from_dir(-5i32));
+        assert_eq!((255u8, 1), u8::saturating_from_dir(300i32));
+        assert_eq!((200u8, 0), u8::saturating_from_dir(200i32));
+        assert_eq!((i16::MIN, -1), i16::saturating_from_dir(f64::NEG_INFINITY));
+        assert_eq!((i16::MAX, 1), i16::saturating_from_dir(1e10f32));
+        assert_eq!((0i16, 0), i16::saturating_from_dir(f32::NAN));
+    }
+}
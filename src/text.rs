@@ -0,0 +1,351 @@
+//! Saturating conversions between numbers and their ASCII text representations.
+
+use crate::{ParseError, SaturatingFrom, SaturatingFromNeg, SaturatingInto};
+
+/// Converts an ASCII decimal digit to its value, returning [`None`] if `byte` is not a digit.
+///
+/// ```
+/// use saturate::text::saturating_from_ascii_digit;
+///
+/// assert_eq!(Some(7), saturating_from_ascii_digit(b'7'));
+/// assert_eq!(None, saturating_from_ascii_digit(b'x'));
+/// ```
+#[inline]
+pub fn saturating_from_ascii_digit(byte: u8) -> Option<u8> {
+    if byte.is_ascii_digit() {
+        Some(byte - b'0')
+    } else {
+        None
+    }
+}
+
+/// Converts a value to an ASCII decimal digit, saturating to `b'0'` or `b'9'` if it is out of
+/// range.
+///
+/// ```
+/// use saturate::text::to_ascii_digit;
+///
+/// assert_eq!(b'7', to_ascii_digit(7));
+/// assert_eq!(b'9', to_ascii_digit(42));
+/// assert_eq!(b'0', to_ascii_digit(-3.5));
+/// ```
+#[inline]
+pub fn to_ascii_digit<T: SaturatingInto<u8>>(value: T) -> u8 {
+    b'0' + value.saturating_into().min(9)
+}
+
+/// Trait to parse an integer from ASCII decimal digits, saturating instead of failing if the value
+/// is out of range.
+///
+/// The input may start with `+`, or with `-` for signed types. Unlike [`str::parse`], the input
+/// does not need to be valid UTF-8 and overflow is not an error.
+///
+/// ```
+/// use saturate::text::SaturatingFromAscii;
+/// use saturate::ParseError;
+///
+/// assert_eq!(Ok(65535), u16::saturating_from_ascii(b"999999999999"));
+/// assert_eq!(Ok(-128), i8::saturating_from_ascii(b"-300"));
+/// assert_eq!(Err(ParseError::InvalidDigit), u8::saturating_from_ascii(b"12a"));
+/// assert_eq!(Some((255, &b"abc"[..])), u8::saturating_parse_prefix(b"300abc"));
+/// assert_eq!(Ok(255), u8::saturating_from_radix_str("0x1FF"));
+/// ```
+pub trait SaturatingFromAscii: Sized {
+    /// Parses `bytes` as a decimal integer, saturating if it is out of range
+    fn saturating_from_ascii(bytes: &[u8]) -> Result<Self, ParseError>;
+
+    /// Parses a decimal integer from the start of `bytes`, saturating if it is out of range, and
+    /// returns it along with the remaining bytes. Returns [`None`] if `bytes` does not start with
+    /// at least one digit, after an optional sign.
+    fn saturating_parse_prefix(bytes: &[u8]) -> Option<(Self, &[u8])>;
+
+    /// Parses `s` as an integer, saturating if it is out of range. The digits are hexadecimal if
+    /// prefixed with `0x`, octal if prefixed with `0o`, binary if prefixed with `0b`, or decimal
+    /// otherwise. Any sign comes before the prefix, e.g. `-0x80`.
+    fn saturating_from_radix_str(s: &str) -> Result<Self, ParseError>;
+}
+
+// Parses the leading digits of `bytes` (without a sign) in the given radix, saturating at
+// u128::MAX, and returns the value along with the remaining bytes
+fn parse_prefix(bytes: &[u8], radix: u32) -> Option<(u128, &[u8])> {
+    let len = bytes
+        .iter()
+        .take_while(|&&byte| char::from(byte).is_digit(radix))
+        .count();
+    if len == 0 {
+        return None;
+    }
+    let (digits, rest) = bytes.split_at(len);
+    let value = digits.iter().fold(0u128, |acc, &byte| {
+        let digit = char::from(byte).to_digit(radix).unwrap_or(0);
+        acc.saturating_mul(u128::from(radix))
+            .saturating_add(u128::from(digit))
+    });
+    Some((value, rest))
+}
+
+// Parses the digits of `bytes` (without a sign) in the given radix, saturating at u128::MAX
+fn parse_magnitude(bytes: &[u8], radix: u32) -> Result<u128, ParseError> {
+    match parse_prefix(bytes, radix) {
+        Some((value, [])) => Ok(value),
+        _ => Err(ParseError::InvalidDigit),
+    }
+}
+
+// Parses the digits of `bytes` (without a sign) in the radix given by its prefix, saturating at
+// u128::MAX
+fn parse_radix_magnitude(bytes: &[u8]) -> Result<u128, ParseError> {
+    match bytes {
+        [b'0', b'x' | b'X', digits @ ..] => parse_magnitude(digits, 16),
+        [b'0', b'o' | b'O', digits @ ..] => parse_magnitude(digits, 8),
+        [b'0', b'b' | b'B', digits @ ..] => parse_magnitude(digits, 2),
+        digits => parse_magnitude(digits, 10),
+    }
+}
+
+macro_rules! impl_from_ascii_unsigned {
+    ($($dst:ty),+) => {
+        $(
+            impl SaturatingFromAscii for $dst {
+                fn saturating_from_ascii(bytes: &[u8]) -> Result<$dst, ParseError> {
+                    let digits = match bytes {
+                        [] => return Err(ParseError::Empty),
+                        [b'+', digits @ ..] => digits,
+                        digits => digits,
+                    };
+                    parse_magnitude(digits, 10).map(<$dst>::saturating_from)
+                }
+
+                fn saturating_parse_prefix(bytes: &[u8]) -> Option<($dst, &[u8])> {
+                    let digits = match bytes {
+                        [b'+', digits @ ..] => digits,
+                        digits => digits,
+                    };
+                    parse_prefix(digits, 10).map(|(value, rest)| (<$dst>::saturating_from(value), rest))
+                }
+
+                fn saturating_from_radix_str(s: &str) -> Result<$dst, ParseError> {
+                    let digits = match s.as_bytes() {
+                        [] => return Err(ParseError::Empty),
+                        [b'+', digits @ ..] => digits,
+                        digits => digits,
+                    };
+                    parse_radix_magnitude(digits).map(<$dst>::saturating_from)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_from_ascii_signed {
+    ($($dst:ty),+) => {
+        $(
+            impl SaturatingFromAscii for $dst {
+                fn saturating_from_ascii(bytes: &[u8]) -> Result<$dst, ParseError> {
+                    match bytes {
+                        [] => Err(ParseError::Empty),
+                        [b'-', digits @ ..] => parse_magnitude(digits, 10).map(<$dst>::saturating_neg_from),
+                        [b'+', digits @ ..] => parse_magnitude(digits, 10).map(<$dst>::saturating_from),
+                        digits => parse_magnitude(digits, 10).map(<$dst>::saturating_from),
+                    }
+                }
+
+                fn saturating_parse_prefix(bytes: &[u8]) -> Option<($dst, &[u8])> {
+                    let (value, rest) = match bytes {
+                        [b'-', digits @ ..] => {
+                            let (value, rest) = parse_prefix(digits, 10)?;
+                            (<$dst>::saturating_neg_from(value), rest)
+                        }
+                        [b'+', digits @ ..] | digits => {
+                            let (value, rest) = parse_prefix(digits, 10)?;
+                            (<$dst>::saturating_from(value), rest)
+                        }
+                    };
+                    Some((value, rest))
+                }
+
+                fn saturating_from_radix_str(s: &str) -> Result<$dst, ParseError> {
+                    match s.as_bytes() {
+                        [] => Err(ParseError::Empty),
+                        [b'-', digits @ ..] => parse_radix_magnitude(digits).map(<$dst>::saturating_neg_from),
+                        [b'+', digits @ ..] | digits => parse_radix_magnitude(digits).map(<$dst>::saturating_from),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_ascii_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_ascii_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ascii_digit() {
+        for (i, byte) in (b'0'..=b'9').enumerate() {
+            assert_eq!(Some(i as u8), saturating_from_ascii_digit(byte));
+        }
+        for &byte in &[0, b' ', b'/', b':', b'a', b'A', 0x7f, 0xb9, 0xff] {
+            assert_eq!(None, saturating_from_ascii_digit(byte));
+        }
+    }
+
+    #[test]
+    fn ascii_digit() {
+        for i in 0..=9u8 {
+            assert_eq!(b'0' + i, to_ascii_digit(i));
+            assert_eq!(Some(i), saturating_from_ascii_digit(to_ascii_digit(i)));
+        }
+        assert_eq!(b'9', to_ascii_digit(10u8));
+        assert_eq!(b'9', to_ascii_digit(u128::MAX));
+        assert_eq!(b'0', to_ascii_digit(i64::MIN));
+        assert_eq!(b'3', to_ascii_digit(3.9f32));
+        assert_eq!(b'0', to_ascii_digit(f64::NAN));
+    }
+
+    #[test]
+    fn from_ascii() {
+        assert_eq!(Ok(0u8), u8::saturating_from_ascii(b"0"));
+        assert_eq!(Ok(42u8), u8::saturating_from_ascii(b"+42"));
+        assert_eq!(Ok(7u32), u32::saturating_from_ascii(b"0007"));
+        assert_eq!(Ok(-42i16), i16::saturating_from_ascii(b"-42"));
+        assert_eq!(Ok(42i16), i16::saturating_from_ascii(b"+42"));
+        assert_eq!(Ok(0i64), i64::saturating_from_ascii(b"-0"));
+        assert_eq!(
+            Ok(i128::MIN),
+            i128::saturating_from_ascii(b"-170141183460469231731687303715884105728")
+        );
+        assert_eq!(
+            Ok(u128::MAX),
+            u128::saturating_from_ascii(b"340282366920938463463374607431768211455")
+        );
+    }
+
+    #[test]
+    fn from_ascii_saturate() {
+        assert_eq!(Ok(65535u16), u16::saturating_from_ascii(b"999999999999"));
+        assert_eq!(Ok(255u8), u8::saturating_from_ascii(b"256"));
+        assert_eq!(Ok(127i8), i8::saturating_from_ascii(b"128"));
+        assert_eq!(Ok(-128i8), i8::saturating_from_ascii(b"-129"));
+        assert_eq!(
+            Ok(u128::MAX),
+            u128::saturating_from_ascii(b"99999999999999999999999999999999999999999999")
+        );
+        assert_eq!(
+            Ok(i128::MIN),
+            i128::saturating_from_ascii(b"-99999999999999999999999999999999999999999999")
+        );
+    }
+
+    #[test]
+    fn from_ascii_invalid() {
+        assert_eq!(Err(ParseError::Empty), u8::saturating_from_ascii(b""));
+        assert_eq!(Err(ParseError::Empty), i8::saturating_from_ascii(b""));
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            u8::saturating_from_ascii(b"+")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            i8::saturating_from_ascii(b"-")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            u8::saturating_from_ascii(b"-1")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            i32::saturating_from_ascii(b"--1")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            i32::saturating_from_ascii(b" 1")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            u64::saturating_from_ascii(b"1_000")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            u64::saturating_from_ascii(b"1.5")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidDigit),
+            u64::saturating_from_ascii(&[b'1', 0xb9])
+        );
+    }
+
+    #[test]
+    fn parse_prefix() {
+        assert_eq!(
+            Some((255u8, &b"abc"[..])),
+            u8::saturating_parse_prefix(b"300abc")
+        );
+        assert_eq!(Some((42u8, &b""[..])), u8::saturating_parse_prefix(b"42"));
+        assert_eq!(
+            Some((7u32, &b",8"[..])),
+            u32::saturating_parse_prefix(b"+007,8")
+        );
+        assert_eq!(
+            Some((-128i8, &b" "[..])),
+            i8::saturating_parse_prefix(b"-300 ")
+        );
+        assert_eq!(
+            Some((5i8, &b"-3"[..])),
+            i8::saturating_parse_prefix(b"+5-3")
+        );
+        assert_eq!(
+            Some((u128::MAX, &b"."[..])),
+            u128::saturating_parse_prefix(b"99999999999999999999999999999999999999999999.")
+        );
+
+        assert_eq!(None, u8::saturating_parse_prefix(b""));
+        assert_eq!(None, u8::saturating_parse_prefix(b"abc"));
+        assert_eq!(None, u8::saturating_parse_prefix(b"+"));
+        assert_eq!(None, u8::saturating_parse_prefix(b"-5"));
+        assert_eq!(None, i8::saturating_parse_prefix(b"-"));
+        assert_eq!(None, i8::saturating_parse_prefix(b"--5"));
+        assert_eq!(None, i8::saturating_parse_prefix(b" 5"));
+    }
+
+    #[test]
+    fn from_radix_str() {
+        assert_eq!(Ok(255u8), u8::saturating_from_radix_str("0x1FF"));
+        assert_eq!(Ok(10u8), u8::saturating_from_radix_str("0b1010"));
+        assert_eq!(Ok(63u8), u8::saturating_from_radix_str("0o77"));
+        assert_eq!(Ok(42u8), u8::saturating_from_radix_str("+42"));
+        assert_eq!(Ok(0u8), u8::saturating_from_radix_str("0"));
+        assert_eq!(Ok(0xabcdu32), u32::saturating_from_radix_str("0XaBcD"));
+        assert_eq!(Ok(-128i8), i8::saturating_from_radix_str("-0x80"));
+        assert_eq!(Ok(-128i8), i8::saturating_from_radix_str("-0x81"));
+        assert_eq!(Ok(127i8), i8::saturating_from_radix_str("0b11111111"));
+        assert_eq!(Ok(5i16), i16::saturating_from_radix_str("+0B101"));
+        assert_eq!(
+            Ok(u128::MAX),
+            u128::saturating_from_radix_str("0xffffffffffffffffffffffffffffffffff")
+        );
+    }
+
+    #[test]
+    fn from_radix_str_invalid() {
+        assert_eq!(Err(ParseError::Empty), u8::saturating_from_radix_str(""));
+        assert_eq!(Err(ParseError::Empty), i8::saturating_from_radix_str(""));
+        for s in &[
+            "0x", "-0x", "0b2", "0o8", "0xg", "x1", "-1", "0x-1", "1_0", "0h1",
+        ] {
+            assert_eq!(
+                Err(ParseError::InvalidDigit),
+                u8::saturating_from_radix_str(s)
+            );
+        }
+        for s in &["-", "--0x1", "0x+1", "- 1", "0xff.0"] {
+            assert_eq!(
+                Err(ParseError::InvalidDigit),
+                i8::saturating_from_radix_str(s)
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::time::{Instant, SystemTime};
+
+use crate::SaturatingFrom;
+
+/// Returns the number of whole milliseconds elapsed between `earlier` and `later`, saturating to
+/// `Dst`'s maximum if it is out of range.
+///
+/// Returns zero if `later` is before `earlier`.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use saturate::saturating_millis_since;
+///
+/// let start = Instant::now();
+/// assert_eq!(1500, saturating_millis_since::<u32>(start, start + Duration::from_millis(1500)));
+/// assert_eq!(255, saturating_millis_since::<u8>(start, start + Duration::from_millis(1500)));
+/// assert_eq!(0, saturating_millis_since::<u32>(start + Duration::from_secs(1), start));
+/// ```
+#[inline]
+pub fn saturating_millis_since<Dst: SaturatingFrom<u128>>(earlier: Instant, later: Instant) -> Dst {
+    Dst::saturating_from(later.saturating_duration_since(earlier).as_millis())
+}
+
+/// Returns the number of whole seconds between the Unix epoch and `time`, saturating to `Dst`'s
+/// maximum if it is out of range.
+///
+/// Returns zero if `time` is before the epoch.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use saturate::saturating_unix_secs;
+///
+/// assert_eq!(86400, saturating_unix_secs::<u32>(UNIX_EPOCH + Duration::from_secs(86400)));
+/// assert_eq!(0, saturating_unix_secs::<u32>(UNIX_EPOCH - Duration::from_secs(1)));
+/// ```
+#[inline]
+pub fn saturating_unix_secs<Dst: SaturatingFrom<u64>>(time: SystemTime) -> Dst {
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    Dst::saturating_from(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn millis_since() {
+        let start = Instant::now();
+        let later = start + Duration::from_millis(40_000);
+        assert_eq!(40_000u16, saturating_millis_since(start, later));
+        assert_eq!(40_000i64, saturating_millis_since(start, later));
+        assert_eq!(i8::MAX, saturating_millis_since(start, later));
+
+        let later = start + Duration::from_secs(100);
+        assert_eq!(u16::MAX, saturating_millis_since(start, later));
+        assert_eq!(100_000u32, saturating_millis_since(start, later));
+
+        let later = start + Duration::from_micros(1999);
+        assert_eq!(1u8, saturating_millis_since(start, later));
+        assert_eq!(0u8, saturating_millis_since(later, start));
+        assert_eq!(0i32, saturating_millis_since(start, start));
+    }
+
+    #[test]
+    fn unix_secs() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        assert_eq!(0u32, saturating_unix_secs(epoch));
+        assert_eq!(
+            1u8,
+            saturating_unix_secs(epoch + Duration::from_millis(1999))
+        );
+        assert_eq!(
+            1_700_000_000i64,
+            saturating_unix_secs(epoch + Duration::from_secs(1_700_000_000))
+        );
+
+        // Before the epoch
+        assert_eq!(0u32, saturating_unix_secs(epoch - Duration::from_secs(1)));
+        assert_eq!(0i64, saturating_unix_secs(epoch - Duration::from_nanos(1)));
+
+        // Far future, past 2106 when u32 seconds overflow
+        let future = epoch + Duration::from_secs(u64::from(u32::MAX) + 1);
+        assert_eq!(u32::MAX, saturating_unix_secs(future));
+        assert_eq!(i32::MAX, saturating_unix_secs(future));
+        assert_eq!(1u64 << 32, saturating_unix_secs(future));
+    }
+}
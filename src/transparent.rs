@@ -0,0 +1,123 @@
+/// Trait for single-field numeric wrappers. Together with
+/// [`impl_transparent!`](crate::impl_transparent), this implements
+/// [`SaturatingFrom`](crate::SaturatingFrom) by converting to the inner type and then wrapping the
+/// result.
+///
+/// This is a lightweight alternative to a derive macro for newtypes such as port numbers or
+/// identifiers.
+///
+/// Implementations must uphold the following contract:
+///
+/// * [`from_inner`](TransparentNumeric::from_inner) must accept every value of
+///   [`Inner`](TransparentNumeric::Inner); wrappers which restrict the range of their inner type
+///   should not implement this trait, as saturation is only performed against the bounds of the
+///   inner type.
+/// * [`into_inner`](TransparentNumeric::into_inner) must return the value passed to
+///   [`from_inner`](TransparentNumeric::from_inner), so that a round trip is lossless.
+///
+/// ```
+/// use saturate::{impl_transparent, SaturatingFrom, TransparentNumeric};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// impl TransparentNumeric for Port {
+///     type Inner = u16;
+///
+///     fn from_inner(inner: u16) -> Self {
+///         Port(inner)
+///     }
+///
+///     fn into_inner(self) -> u16 {
+///         self.0
+///     }
+/// }
+///
+/// impl_transparent!(Port);
+///
+/// assert_eq!(Port(8080), Port::saturating_from(8080u32));
+/// assert_eq!(Port(65535), Port::saturating_from(100_000u32));
+/// assert_eq!(Port(0), Port::saturating_from(-1i32));
+/// ```
+pub trait TransparentNumeric: Sized {
+    /// The wrapped numeric type
+    type Inner;
+
+    /// Wraps a value of the inner type
+    fn from_inner(inner: Self::Inner) -> Self;
+
+    /// Unwraps the inner value
+    fn into_inner(self) -> Self::Inner;
+}
+
+/// Implements [`SaturatingFrom`](crate::SaturatingFrom) for a list of types implementing
+/// [`TransparentNumeric`](crate::TransparentNumeric), from every type their inner type can be
+/// converted from.
+///
+/// See [`TransparentNumeric`](crate::TransparentNumeric) for an example. A blanket implementation
+/// over every `TransparentNumeric` type would prevent other crates from implementing
+/// `SaturatingFrom` for the primitive types, so each type is implemented individually.
+#[macro_export]
+macro_rules! impl_transparent {
+    ($($typ:ty),+ $(,)?) => {
+        $(
+            impl<T> $crate::SaturatingFrom<T> for $typ
+            where
+                <$typ as $crate::TransparentNumeric>::Inner: $crate::SaturatingFrom<T>,
+            {
+                const IS_WIDENING: bool = <
+                    <$typ as $crate::TransparentNumeric>::Inner as $crate::SaturatingFrom<T>
+                >::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: T) -> $typ {
+                    <$typ as $crate::TransparentNumeric>::from_inner(
+                        $crate::SaturatingFrom::saturating_from(value),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingFrom;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Port(u16);
+
+    impl TransparentNumeric for Port {
+        type Inner = u16;
+
+        fn from_inner(inner: u16) -> Self {
+            Port(inner)
+        }
+
+        fn into_inner(self) -> u16 {
+            self.0
+        }
+    }
+
+    impl_transparent!(Port);
+
+    #[test]
+    fn transparent_numeric() {
+        assert_eq!(Port(0), Port::saturating_from(0u32));
+        assert_eq!(Port(443), Port::saturating_from(443u32));
+        assert_eq!(Port(65535), Port::saturating_from(65535u32));
+        assert_eq!(Port(65535), Port::saturating_from(65536u32));
+        assert_eq!(Port(65535), Port::saturating_from(u32::MAX));
+        assert_eq!(Port(0), Port::saturating_from(-80i64));
+        assert_eq!(Port(0), Port::saturating_from(f32::NAN));
+        assert_eq!(Port(65535), Port::saturating_from(1e9f64));
+
+        fn is_widening<T: SaturatingFrom<U>, U>() -> bool {
+            T::IS_WIDENING
+        }
+        assert!(is_widening::<Port, u8>());
+        assert!(!is_widening::<Port, u32>());
+        assert_eq!(8080, Port::saturating_from(8080u32).into_inner());
+    }
+}
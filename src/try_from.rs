@@ -0,0 +1,184 @@
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+
+use crate::classify::{Classify, Outcome};
+use crate::error::Clamped;
+use crate::SaturatingFrom;
+
+/// Trait to perform a checked conversion between integer types with the same error as
+/// [`TryFrom`].
+///
+/// This is intended to help migrate code from [`TryFrom`] to [`SaturatingFrom`]. Calls to
+/// `try_from` can be replaced with `saturating_or_error` without changing any error handling, and
+/// then later switched to `saturating_from` once saturation is the desired behaviour.
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use saturate::SaturatingOrError;
+///
+/// assert_eq!(Ok(100), u8::saturating_or_error(100i32));
+/// assert_eq!(u8::try_from(300i32), u8::saturating_or_error(300i32));
+/// ```
+pub trait SaturatingOrError<T>: SaturatingFrom<T> + Sized {
+    /// Converts the input type `T` to `Self`, returning an error if `value` is out of range
+    fn saturating_or_error(value: T) -> Result<Self, TryFromIntError>;
+}
+
+macro_rules! impl_or_error {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl SaturatingOrError<$src> for $dst {
+                #[inline]
+                fn saturating_or_error(value: $src) -> Result<$dst, TryFromIntError> {
+                    // Lossless conversions have an `Infallible` error, which converts to any error
+                    <$dst>::try_from(value).map_err(TryFromIntError::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u8);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u16);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u32);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u64);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => u128);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => usize);
+
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i8);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i16);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i32);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i64);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => i128);
+impl_or_error!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => isize);
+
+/// Trait to perform a conversion between integer types where the handling of out of range values
+/// is selected at compile time.
+///
+/// This is a middle ground between [`SaturatingFrom`] and [`TryFrom`]: with `ERR_ON_OVERFLOW`
+/// set to `false` the conversion saturates and always returns [`Ok`], and with it set to `true`
+/// out of range values return the same error as [`TryFrom`]. The flag is a const generic, so the
+/// choice has no runtime cost.
+///
+/// ```
+/// use saturate::SaturatingFromMode;
+///
+/// assert_eq!(Ok(255), u8::saturating_from_mode::<false>(300i32));
+/// assert!(u8::saturating_from_mode::<true>(300i32).is_err());
+/// ```
+pub trait SaturatingFromMode<T>: SaturatingOrError<T> {
+    /// Converts the input type `T` to `Self`, either saturating or returning an error if `value`
+    /// is out of range depending on `ERR_ON_OVERFLOW`
+    fn saturating_from_mode<const ERR_ON_OVERFLOW: bool>(value: T)
+        -> Result<Self, TryFromIntError>;
+}
+
+impl<T, U: SaturatingOrError<T>> SaturatingFromMode<T> for U {
+    #[inline]
+    fn saturating_from_mode<const ERR_ON_OVERFLOW: bool>(
+        value: T,
+    ) -> Result<Self, TryFromIntError> {
+        if ERR_ON_OVERFLOW {
+            U::saturating_or_error(value)
+        } else {
+            Ok(U::saturating_from(value))
+        }
+    }
+}
+
+/// Extension trait to perform a saturating conversion into an integer type, returning an error
+/// containing the saturated value if it had to be clamped.
+///
+/// This allows out of range values to be handled with `?` while still having access to the
+/// saturated value, e.g. to log it before continuing. NaN is also reported as [`Clamped`], with a
+/// value of zero. Values which are only rounded (e.g. `2.5` into [`u8`]) are not an error.
+///
+/// ```
+/// use saturate::{Clamped, SaturatingIntoChecked};
+///
+/// assert_eq!(Ok(100u8), 100i32.saturating_into_checked());
+/// assert_eq!(Err(Clamped { value: 255u8 }), 300i32.saturating_into_checked());
+///
+/// fn parse(value: i64) -> Result<u8, u8> {
+///     let byte: u8 = value.saturating_into_checked().map_err(|c| c.value)?;
+///     Ok(byte)
+/// }
+/// assert_eq!(Err(0), parse(-1));
+/// ```
+pub trait SaturatingIntoChecked<T>: Sized {
+    /// Converts `self` to the output type `T`, returning [`Clamped`] if `self` was out of range
+    fn saturating_into_checked(self) -> Result<T, Clamped<T>>;
+}
+
+impl<T, U: Classify<T>> SaturatingIntoChecked<U> for T {
+    #[inline]
+    fn saturating_into_checked(self) -> Result<U, Clamped<U>> {
+        match U::classify(self) {
+            (value, Outcome::Exact | Outcome::Rounded) => Ok(value),
+            (value, Outcome::Overflow | Outcome::Underflow | Outcome::Nan) => {
+                Err(Clamped { value })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_or_error() {
+        assert_eq!(Ok(100u8), u8::saturating_or_error(100i32));
+        assert_eq!(Ok(200u16), u16::saturating_or_error(200u8));
+        assert_eq!(Ok(-5i64), i64::saturating_or_error(-5i64));
+        assert_eq!(Ok(u64::MAX), u64::saturating_or_error(u128::from(u64::MAX)));
+
+        assert_eq!(u8::try_from(300i32), u8::saturating_or_error(300i32));
+        assert_eq!(u32::try_from(-1i8), u32::saturating_or_error(-1i8));
+        assert_eq!(i8::try_from(u128::MAX), i8::saturating_or_error(u128::MAX));
+        assert_eq!(
+            usize::try_from(-1isize),
+            usize::saturating_or_error(-1isize)
+        );
+        assert!(i16::saturating_or_error(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn saturating_from_mode() {
+        assert_eq!(Ok(100u8), u8::saturating_from_mode::<false>(100i32));
+        assert_eq!(Ok(255u8), u8::saturating_from_mode::<false>(300i32));
+        assert_eq!(Ok(0u32), u32::saturating_from_mode::<false>(-1i8));
+        assert_eq!(Ok(i8::MIN), i8::saturating_from_mode::<false>(i128::MIN));
+
+        assert_eq!(Ok(100u8), u8::saturating_from_mode::<true>(100i32));
+        assert_eq!(
+            u8::try_from(300i32),
+            u8::saturating_from_mode::<true>(300i32)
+        );
+        assert_eq!(u32::try_from(-1i8), u32::saturating_from_mode::<true>(-1i8));
+        assert!(i8::saturating_from_mode::<true>(i128::MIN).is_err());
+    }
+
+    #[test]
+    fn saturating_into_checked() {
+        assert_eq!(Ok(100u8), 100i32.saturating_into_checked());
+        assert_eq!(Ok(-5i64), (-5i8).saturating_into_checked());
+        assert_eq!(Ok(2u8), 2.5f32.saturating_into_checked());
+        assert_eq!(Ok(1u16), true.saturating_into_checked());
+
+        let result: Result<u8, _> = 300i32.saturating_into_checked();
+        assert_eq!(Err(Clamped { value: 255 }), result);
+        let result: Result<u32, _> = (-1i8).saturating_into_checked();
+        assert_eq!(0, result.unwrap_err().value);
+        let result: Result<i8, _> = f64::NEG_INFINITY.saturating_into_checked();
+        assert_eq!(Err(Clamped { value: i8::MIN }), result);
+        let result: Result<u64, _> = f32::NAN.saturating_into_checked();
+        assert_eq!(Err(Clamped { value: 0 }), result);
+
+        let error: Result<u8, _> = 1000u16.saturating_into_checked();
+        assert_eq!(
+            "value out of range, clamped to 255",
+            error.unwrap_err().to_string()
+        );
+    }
+}
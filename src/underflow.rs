@@ -0,0 +1,60 @@
+use core::num::FpCategory;
+
+use crate::SaturatingFrom;
+
+/// Converts `value` to `f32`, also returning whether it underflowed.
+///
+/// Underflow is reported when a nonzero `value` converts to zero, or to a subnormal `f32` which
+/// has less precision than a normal one. Zero, infinity and NaN never underflow.
+///
+/// ```
+/// use saturate::saturating_from_checked_underflow;
+///
+/// assert_eq!((0.0, true), saturating_from_checked_underflow(1e-50));
+/// assert_eq!((1.5, false), saturating_from_checked_underflow(1.5));
+/// ```
+#[inline]
+pub fn saturating_from_checked_underflow(value: f64) -> (f32, bool) {
+    let result = f32::saturating_from(value);
+    let underflow = match result.classify() {
+        FpCategory::Subnormal => true,
+        FpCategory::Zero => value != 0.0,
+        FpCategory::Normal | FpCategory::Infinite | FpCategory::Nan => false,
+    };
+    (result, underflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_underflow() {
+        assert_eq!((0.0, true), saturating_from_checked_underflow(1e-50));
+        assert_eq!((-0.0, true), saturating_from_checked_underflow(-1e-50));
+        assert_eq!(
+            (0.0, true),
+            saturating_from_checked_underflow(f64::from_bits(1))
+        );
+
+        let (result, underflow) = saturating_from_checked_underflow(1e-40);
+        assert!(underflow);
+        assert_eq!(FpCategory::Subnormal, result.classify());
+
+        let min = f64::from(f32::MIN_POSITIVE);
+        assert_eq!(
+            (f32::MIN_POSITIVE, false),
+            saturating_from_checked_underflow(min)
+        );
+        assert_eq!((1.5, false), saturating_from_checked_underflow(1.5));
+        assert_eq!((0.0, false), saturating_from_checked_underflow(0.0));
+        assert_eq!(
+            (f32::INFINITY, false),
+            saturating_from_checked_underflow(1e300)
+        );
+
+        let (result, underflow) = saturating_from_checked_underflow(f64::NAN);
+        assert!(result.is_nan());
+        assert!(!underflow);
+    }
+}
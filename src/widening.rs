@@ -0,0 +1,100 @@
+use crate::SaturatingFrom;
+
+/// Marker trait for conversions which are lossless and never saturate.
+///
+/// This is implemented only for pairs where every value of `T` is exactly representable as
+/// `Self` on every supported target. Conversions involving [`isize`] or [`usize`] are therefore
+/// only included where they are lossless for any pointer width from 16 to 64 bits, so
+/// `usize: Widening<u32>` is not implemented even on 64-bit targets.
+///
+/// Bounding on this trait (or using [`widen`]) statically forbids narrowing conversions.
+pub trait Widening<T>: SaturatingFrom<T> {}
+
+macro_rules! impl_widening {
+    ([$($src:ty),+] => $dst:ty) => {
+        $(
+            impl Widening<$src> for $dst {}
+        )+
+    };
+}
+
+impl_widening!([bool] => bool);
+
+impl_widening!([bool, u8] => u8);
+impl_widening!([bool, u8, u16] => u16);
+impl_widening!([bool, u8, u16, u32] => u32);
+impl_widening!([bool, u8, u16, u32, u64, usize] => u64);
+impl_widening!([bool, u8, u16, u32, u64, u128, usize] => u128);
+impl_widening!([bool, u8, u16, usize] => usize);
+
+impl_widening!([bool, i8] => i8);
+impl_widening!([bool, i8, u8, i16] => i16);
+impl_widening!([bool, i8, u8, i16, u16, i32] => i32);
+impl_widening!([bool, i8, u8, i16, u16, i32, u32, i64, isize] => i64);
+impl_widening!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, isize, usize] => i128);
+impl_widening!([bool, i8, u8, i16, isize] => isize);
+
+impl_widening!([bool, i8, u8, i16, u16, f32] => f32);
+impl_widening!([bool, i8, u8, i16, u16, i32, u32, f32, f64] => f64);
+
+/// Performs a lossless conversion from `Src` to `Dst`, failing to compile if the conversion could
+/// saturate or round.
+///
+/// ```
+/// assert_eq!(300u16, saturate::widen::<u16, u8>(255u8) + 45);
+/// assert_eq!(-1.0f64, saturate::widen(-1i32));
+/// ```
+///
+/// Narrowing conversions are rejected:
+///
+/// ```compile_fail
+/// let narrow: u8 = saturate::widen::<u8, u16>(300);
+/// ```
+#[inline]
+pub fn widen<Dst: Widening<Src>, Src>(value: Src) -> Dst {
+    Dst::saturating_from(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_widening() {
+        fn is_widening<T: Widening<U>, U>() -> bool {
+            T::IS_WIDENING
+        }
+
+        macro_rules! check_widening {
+            ([$($src:ty),+] => $dst:ty) => {
+                $(assert!(is_widening::<$dst, $src>(), "{} => {}", stringify!($src), stringify!($dst));)+
+            };
+        }
+
+        check_widening!([bool] => bool);
+        check_widening!([bool, u8] => u8);
+        check_widening!([bool, u8, u16] => u16);
+        check_widening!([bool, u8, u16, u32] => u32);
+        check_widening!([bool, u8, u16, u32, u64, usize] => u64);
+        check_widening!([bool, u8, u16, u32, u64, u128, usize] => u128);
+        check_widening!([bool, u8, u16, usize] => usize);
+        check_widening!([bool, i8] => i8);
+        check_widening!([bool, i8, u8, i16] => i16);
+        check_widening!([bool, i8, u8, i16, u16, i32] => i32);
+        check_widening!([bool, i8, u8, i16, u16, i32, u32, i64, isize] => i64);
+        check_widening!([bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, isize, usize] => i128);
+        check_widening!([bool, i8, u8, i16, isize] => isize);
+        check_widening!([bool, i8, u8, i16, u16, f32] => f32);
+        check_widening!([bool, i8, u8, i16, u16, i32, u32, f32, f64] => f64);
+    }
+
+    #[test]
+    fn widen() {
+        assert_eq!(255u16, super::widen::<u16, u8>(255));
+        assert_eq!(-128i64, super::widen::<i64, i8>(-128));
+        assert_eq!(u64::MAX as u128, super::widen::<u128, _>(u64::MAX));
+        assert_eq!(1.0f32, super::widen::<f32, _>(true));
+        assert_eq!(65535usize, super::widen::<usize, _>(u16::MAX));
+        assert_eq!(0.1f32 as f64, super::widen::<f64, _>(0.1f32));
+    }
+}
@@ -0,0 +1,81 @@
+use core::num::Wrapping;
+
+use crate::SaturatingFrom;
+
+/// Integer types, the only types for which [`Wrapping`] arithmetic is defined.
+///
+/// Although `Wrapping<f32>` can be constructed it never wraps, so converting it is almost
+/// certainly a mistake and is rejected at compile time:
+///
+/// ```compile_fail
+/// use core::num::Wrapping;
+/// use saturate::SaturatingFrom;
+///
+/// let value = u8::saturating_from(Wrapping(1.5f32));
+/// ```
+pub trait WrappingInteger {}
+
+macro_rules! impl_wrapping_integer {
+    ($($typ:ty),+) => {
+        $(
+            impl WrappingInteger for $typ {}
+        )+
+    };
+}
+
+impl_wrapping_integer!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_from_wrapping {
+    ($($dst:ty),+) => {
+        $(
+            /// Converts the wrapped value. Any wrapping has already happened in the arithmetic
+            /// that produced it, so the conversion clamps rather than wraps, e.g.
+            /// `Wrapping(-1i64)` converts to `0u8`, not `255u8`. Only integer values are accepted
+            impl<T> SaturatingFrom<Wrapping<T>> for $dst
+            where
+                T: WrappingInteger,
+                $dst: SaturatingFrom<T>,
+            {
+                const IS_WIDENING: bool = <$dst as SaturatingFrom<T>>::IS_WIDENING;
+
+                #[inline]
+                fn saturating_from(value: Wrapping<T>) -> $dst {
+                    <$dst>::saturating_from(value.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_wrapping!(bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f64, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaturatingInto;
+
+    #[test]
+    fn impl_from_wrapping() {
+        assert_eq!(0u8, u8::saturating_from(Wrapping(-1i64)));
+        assert_eq!(255u8, u8::saturating_from(Wrapping(1000u32)));
+        assert_eq!(-128i8, Wrapping(i64::MIN).saturating_into());
+        assert_eq!(7u16, u16::saturating_from(Wrapping(7i8)));
+        assert!(bool::saturating_from(Wrapping(3u64)));
+    }
+
+    #[test]
+    fn wraps_then_clamps() {
+        // The arithmetic wraps around to i64::MIN, which then clamps to the bottom of the range
+        let value = Wrapping(i64::MAX) + Wrapping(1);
+        assert_eq!(i64::MIN, value.0);
+        assert_eq!(0u8, u8::saturating_from(value));
+        assert_eq!(i16::MIN, i16::saturating_from(value));
+
+        // Whereas without wrapping the value would have clamped to the top
+        assert_eq!(255u8, u8::saturating_from(i128::from(i64::MAX) + 1));
+
+        let value = Wrapping(0u32) - Wrapping(1);
+        assert_eq!(255u8, u8::saturating_from(value));
+        assert_eq!(u32::MAX, u32::saturating_from(value));
+    }
+}
@@ -0,0 +1,93 @@
+//! Implementations a downstream crate can write for its own types. These must keep compiling, so
+//! the crate can't provide blanket implementations over every `T` which could overlap with them.
+
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+
+use saturate::{
+    impl_conversions, impl_transparent, SaturatingFrom, SaturatingInto, TransparentNumeric,
+};
+
+/// A type with a hand written conversion to a primitive
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Level(i32);
+
+impl SaturatingFrom<Level> for u8 {
+    fn saturating_from(value: Level) -> u8 {
+        u8::saturating_from(value.0)
+    }
+}
+
+impl SaturatingFrom<u8> for Level {
+    const IS_WIDENING: bool = true;
+
+    fn saturating_from(value: u8) -> Level {
+        Level(value.into())
+    }
+}
+
+/// A newtype using the provided implementation
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Port(u16);
+
+impl TransparentNumeric for Port {
+    type Inner = u16;
+
+    fn from_inner(inner: u16) -> Self {
+        Port(inner)
+    }
+
+    fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl_transparent!(Port);
+
+impl SaturatingFrom<Port> for u8 {
+    fn saturating_from(value: Port) -> u8 {
+        u8::saturating_from(value.into_inner())
+    }
+}
+
+/// A type converted to a primitive with the provided macro
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Celsius(i32);
+
+impl TryFrom<Celsius> for i8 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Celsius) -> Result<i8, TryFromIntError> {
+        i8::try_from(value.0)
+    }
+}
+
+impl From<i8> for Celsius {
+    fn from(value: i8) -> Celsius {
+        Celsius(value.into())
+    }
+}
+
+impl_conversions! { Celsius => i8 }
+
+#[test]
+fn local_to_primitive() {
+    assert_eq!(255u8, u8::saturating_from(Level(300)));
+    assert_eq!(0u8, Level(-1).saturating_into());
+    assert_eq!(Level(200), Level::saturating_from(200u8));
+}
+
+#[test]
+fn transparent() {
+    assert_eq!(Port(65535), Port::saturating_from(100_000u32));
+    assert_eq!(Port(0), Port::saturating_from(-1.5f64));
+    assert_eq!(255u8, u8::saturating_from(Port(8080)));
+    assert_eq!(80u8, Port(80).saturating_into());
+}
+
+#[test]
+fn conversions_macro() {
+    assert_eq!(21i8, i8::saturating_from(Celsius(21)));
+    assert_eq!(127i8, i8::saturating_from(Celsius(1000)));
+    assert_eq!(-128i8, Celsius(-1000).saturating_into());
+}
@@ -0,0 +1,185 @@
+//! Classifies every conversion between the standard numeric types by probing representative
+//! values, and checks the result against a table of expected behaviour.
+
+#![cfg(target_pointer_width = "64")]
+
+use saturate::SaturatingFrom;
+
+/// How a conversion treats its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Behaviour {
+    /// Every value is converted exactly
+    Exact,
+    /// Out of range values are clamped to the target's minimum or maximum
+    Clamp,
+    /// Some values are rounded to the nearest representable value
+    Round,
+    /// NaN is converted to zero, with other values truncated and clamped
+    NanToZero,
+}
+
+use Behaviour::{Clamp as C, Exact as E, NanToZero as N, Round as R};
+
+trait Probe: Copy {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+
+    /// Representative values, including the extremes of the type
+    fn samples() -> Vec<Self>;
+
+    /// Equality, treating NaN as equal to itself
+    fn same(self, other: Self) -> bool;
+
+    fn is_nan(self) -> bool;
+}
+
+impl Probe for bool {
+    const MIN: bool = false;
+    const MAX: bool = true;
+    const ZERO: bool = false;
+
+    fn samples() -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn same(self, other: bool) -> bool {
+        self == other
+    }
+
+    fn is_nan(self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_probe_int {
+    ($($typ:ty),+) => {
+        $(
+            impl Probe for $typ {
+                const MIN: $typ = <$typ>::MIN;
+                const MAX: $typ = <$typ>::MAX;
+                const ZERO: $typ = 0;
+
+                fn samples() -> Vec<$typ> {
+                    vec![
+                        <$typ>::MIN, <$typ>::MIN + 1, <$typ>::MIN / 2, 0, 1, 100, <$typ>::MAX / 2,
+                        <$typ>::MAX - 1, <$typ>::MAX,
+                    ]
+                }
+
+                fn same(self, other: $typ) -> bool {
+                    self == other
+                }
+
+                fn is_nan(self) -> bool {
+                    false
+                }
+            }
+        )+
+    };
+}
+
+impl_probe_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_probe_float {
+    ($($typ:ty),+) => {
+        $(
+            impl Probe for $typ {
+                const MIN: $typ = <$typ>::MIN;
+                const MAX: $typ = <$typ>::MAX;
+                const ZERO: $typ = 0.0;
+
+                fn samples() -> Vec<$typ> {
+                    vec![
+                        <$typ>::NAN, <$typ>::NEG_INFINITY, <$typ>::MIN, -1e10, -1.5, -0.0, 0.0,
+                        0.1, 0.5, 1.0, 1e10, <$typ>::MAX, <$typ>::INFINITY,
+                    ]
+                }
+
+                fn same(self, other: $typ) -> bool {
+                    self == other || (self.is_nan() && other.is_nan())
+                }
+
+                fn is_nan(self) -> bool {
+                    <$typ>::is_nan(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_probe_float!(f32, f64);
+
+/// Probes the conversion from `Src` to `Dst`, also checking that `IS_WIDENING` agrees
+fn classify<Src, Dst>() -> Behaviour
+where
+    Src: Probe + SaturatingFrom<Dst>,
+    Dst: Probe + SaturatingFrom<Src>,
+{
+    let samples = Src::samples();
+    let round_trips = |&value: &Src| Src::saturating_from(Dst::saturating_from(value)).same(value);
+    let is_bound = |&value: &Src| {
+        let result = Dst::saturating_from(value);
+        result.same(Dst::MIN) || result.same(Dst::MAX)
+    };
+
+    let behaviour = if samples.iter().all(round_trips) {
+        E
+    } else if samples
+        .iter()
+        .any(|&value| value.is_nan() && Dst::saturating_from(value).same(Dst::ZERO))
+    {
+        N
+    } else if samples
+        .iter()
+        .filter(|value| !round_trips(value))
+        .all(is_bound)
+    {
+        C
+    } else {
+        R
+    };
+    assert_eq!(
+        <Dst as SaturatingFrom<Src>>::IS_WIDENING,
+        behaviour == E,
+        "IS_WIDENING is inconsistent with the probed behaviour"
+    );
+    behaviour
+}
+
+macro_rules! classify_all {
+    ($($src:ty),+) => {
+        [$(classify_all!(@row $src; bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64)),+]
+    };
+    (@row $src:ty; $($dst:ty),+) => {
+        [$(classify::<$src, $dst>()),+]
+    };
+}
+
+#[test]
+fn conversion_matrix() {
+    // Rows are sources and columns are targets, both in the order
+    // bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64
+    #[rustfmt::skip]
+    let expected = [
+        [E, E, E, E, E, E, E, E, E, E, E, E, E, E, E], // bool
+        [C, E, C, E, C, E, C, E, C, E, C, E, C, E, E], // i8
+        [C, C, E, E, E, E, E, E, E, E, E, E, E, E, E], // u8
+        [C, C, C, E, C, E, C, E, C, E, C, E, C, E, E], // i16
+        [C, C, C, C, E, E, E, E, E, E, E, E, E, E, E], // u16
+        [C, C, C, C, C, E, C, E, C, E, C, E, C, R, E], // i32
+        [C, C, C, C, C, C, E, E, E, E, E, E, E, R, E], // u32
+        [C, C, C, C, C, C, C, E, C, E, C, E, C, R, R], // i64
+        [C, C, C, C, C, C, C, C, E, E, E, C, E, R, R], // u64
+        [C, C, C, C, C, C, C, C, C, E, C, C, C, R, R], // i128
+        [C, C, C, C, C, C, C, C, C, C, E, C, C, R, R], // u128
+        [C, C, C, C, C, C, C, E, C, E, C, E, C, R, R], // isize
+        [C, C, C, C, C, C, C, C, E, E, E, C, E, R, R], // usize
+        [N, N, N, N, N, N, N, N, N, N, N, N, N, E, E], // f32
+        [N, N, N, N, N, N, N, N, N, N, N, N, N, R, E], // f64
+    ];
+    let actual = classify_all!(
+        bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64
+    );
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,41 @@
+//! Checks that the documented minimum supported Rust version matches `rust-version` in
+//! Cargo.toml, and exercises the default features which are built on that version in CI.
+//!
+//! `clippy::incompatible_msrv` checks standard library API usage against `rust-version`, so this
+//! only needs to keep the declared version and the documentation in sync.
+
+use saturate::{SaturatingFrom, SaturatingInto, SaturatingIterator};
+
+fn rust_version() -> &'static str {
+    include_str!("../Cargo.toml")
+        .lines()
+        .find_map(|line| line.strip_prefix("rust-version = "))
+        .expect("rust-version is set in Cargo.toml")
+        .trim_matches('"')
+}
+
+#[test]
+fn documented_msrv() {
+    let statement = format!(
+        "The minimum supported Rust version is {} with default features",
+        rust_version()
+    );
+    assert!(include_str!("../README.md").contains(&statement));
+    assert!(include_str!("../src/lib.rs").contains(&statement));
+
+    let badge = format!("rust-{}%2B", rust_version());
+    assert!(include_str!("../README.md").contains(&badge));
+}
+
+#[test]
+fn default_features() {
+    assert_eq!(0u8, u8::saturating_from(-26i32));
+    assert_eq!(u32::MAX, i64::MAX.saturating_into());
+    assert_eq!(i16::MIN, i16::saturating_from(f64::NEG_INFINITY));
+    assert_eq!(0u64, u64::saturating_from(f32::NAN));
+    assert!(f32::saturating_from(u128::MAX).is_infinite());
+
+    // Const generics are the reason for the current minimum version
+    let rgba: Option<[u8; 4]> = [300, -2, 127, 256].iter().copied().saturating_into_array();
+    assert_eq!(Some([255, 0, 127, 255]), rgba);
+}
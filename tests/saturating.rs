@@ -0,0 +1,47 @@
+//! Accumulating sensor readings with `core::num::Saturating`.
+//!
+//! Requires the `num-saturating` feature.
+
+#![cfg(feature = "num-saturating")]
+#![allow(clippy::incompatible_msrv)]
+
+use core::num::Saturating;
+
+use saturate::{SaturatingFrom, SaturatingInto};
+
+/// Sums deltas into a 16-bit accumulator, clamping each delta on conversion and the total on
+/// addition
+fn accumulate(deltas: &[i64]) -> Saturating<u16> {
+    deltas
+        .iter()
+        .map(|&delta| Saturating::<u16>::saturating_from(delta))
+        .fold(Saturating(0), |total, delta| total + delta)
+}
+
+#[test]
+fn accumulate_in_range() {
+    assert_eq!(Saturating(600), accumulate(&[100, 200, 300]));
+}
+
+#[test]
+fn accumulate_saturates_individual_deltas() {
+    // Negative deltas clamp to zero and huge deltas clamp to u16::MAX rather than wrapping
+    assert_eq!(Saturating(10), accumulate(&[-50, 10, -1]));
+    assert_eq!(Saturating(u16::MAX), accumulate(&[i64::MAX]));
+    assert_eq!(Saturating(u16::MAX), accumulate(&[65536 + 1]));
+}
+
+#[test]
+fn accumulate_saturates_total() {
+    // Each delta fits, but the sum would overflow a plain u16
+    let deltas = vec![40_000i64, 30_000, 20_000];
+    let total = accumulate(&deltas);
+    assert_eq!(Saturating(u16::MAX), total);
+
+    // Further arithmetic keeps saturating, and the result converts back out of the wrapper
+    let total = total + Saturating(1);
+    assert_eq!(u16::MAX, total.0);
+    let byte: u8 = total.saturating_into();
+    assert_eq!(u8::MAX, byte);
+    assert_eq!(i16::MAX, i16::saturating_from(total));
+}